@@ -0,0 +1,61 @@
+//! Times parsing and each part separately for every day, on the real input
+//! in `inputs/day_N.txt` when there is one and on the bundled example
+//! otherwise.
+//!
+//! `cargo bench -p aoc -- day_12/` runs a single day. Save a run with
+//! `--save-baseline <name>` and compare a later one against it with
+//! `--baseline <name>`.
+
+use std::{fs, hint::black_box, path::PathBuf};
+
+use common::{normalise, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn input(day: u32) -> String {
+    common::input::read_conventional(day).unwrap_or_else(|_| {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("benches/inputs")
+            .join(format!("day_{}.txt", day));
+        fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("couldn't read {}: {}", path.display(), e))
+    })
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let input = input(S::DAY);
+    let input = normalise(&input);
+    let solution = match S::parse(&input) {
+        Ok(solution) => solution,
+        Err(e) => panic!("{}", e.for_day(S::DAY)),
+    };
+
+    let mut group = c.benchmark_group(format!("day_{}", S::DAY));
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part_1", |b| b.iter(|| black_box(&solution).part_one()));
+    group.bench_function("part_2", |b| b.iter(|| black_box(&solution).part_two()));
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day_1::Day1>(c);
+    bench_day::<day_2::Day2>(c);
+    bench_day::<day_3::Day3>(c);
+    bench_day::<day_4::Day4>(c);
+    bench_day::<day_5::Day5>(c);
+    bench_day::<day_6::Day6>(c);
+    bench_day::<day_7::Day7>(c);
+    bench_day::<day_8::Day8>(c);
+    bench_day::<day_9::Day9>(c);
+    bench_day::<day_10::Day10>(c);
+    bench_day::<day_11::Day11>(c);
+    bench_day::<day_12::Day12>(c);
+    bench_day::<day_13::Day13>(c);
+    bench_day::<day_14::Day14>(c);
+    bench_day::<day_15::Day15>(c);
+    bench_day::<day_16::Day16>(c);
+    bench_day::<day_17::Day17>(c);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
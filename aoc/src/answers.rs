@@ -0,0 +1,158 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use common::{normalise, Part};
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Malformed {
+        path: PathBuf,
+        line: usize,
+    },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Io { path, error } => {
+                write!(f, "couldn't access answers {}: {}", path.display(), error)
+            }
+            AnswersError::Malformed { path, line } => {
+                write!(f, "answers {} line {} is malformed", path.display(), line)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// A hash of the input which is stable between runs and builds, so answers
+/// recorded for one input are only ever compared against the same input.
+/// This is FNV-1a over the normalised input.
+pub fn input_hash(input: &str) -> u64 {
+    normalise(input)
+        .bytes()
+        .fold(0xcbf29ce484222325, |hash, b| {
+            (hash ^ b as u64).wrapping_mul(0x100000001b3)
+        })
+}
+
+/// Known good answers, keyed by day, part and input hash. Stored as
+/// `day<TAB>part<TAB>hash<TAB>answer` lines.
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u32, Part, u64), String>,
+}
+
+impl Answers {
+    /// The answers file in the first of the input directories which exists.
+    pub fn default_path() -> PathBuf {
+        let dirs = common::input::input_dirs();
+        let dir = dirs.iter().find(|x| x.is_dir()).unwrap_or(&dirs[0]);
+
+        dir.join("answers.tsv")
+    }
+
+    /// Loads answers from `path`, which is treated as empty if it doesn't
+    /// exist yet.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|line| AnswersError::Malformed {
+                path: path.to_owned(),
+                line,
+            }),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(AnswersError::Io {
+                path: path.to_owned(),
+                error,
+            }),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        let io_error = |error| AnswersError::Io {
+            path: path.to_owned(),
+            error,
+        };
+
+        if let Some(parent) = path.parent().filter(|x| !x.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        std::fs::write(path, self.to_string()).map_err(io_error)
+    }
+
+    /// Parses the answers file format, giving the first bad line number on
+    /// failure.
+    fn parse(text: &str) -> Result<Self, usize> {
+        let mut answers = BTreeMap::new();
+
+        for (idx, line) in text.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<_> = line.splitn(4, '\t').collect();
+            let &[day, part, hash, answer] = fields.as_slice() else {
+                return Err(idx + 1);
+            };
+
+            let key = (
+                day.parse().map_err(|_| idx + 1)?,
+                part.parse().map_err(|_| idx + 1)?,
+                u64::from_str_radix(hash, 16).map_err(|_| idx + 1)?,
+            );
+            answers.insert(key, answer.to_string());
+        }
+
+        Ok(Self { answers })
+    }
+
+    pub fn get(&self, day: u32, part: Part, hash: u64) -> Option<&str> {
+        self.answers.get(&(day, part, hash)).map(|x| x.as_str())
+    }
+
+    pub fn insert(&mut self, day: u32, part: Part, hash: u64, answer: String) {
+        self.answers.insert((day, part, hash), answer);
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day\tpart\tinput hash\tanswer")?;
+        for ((day, part, hash), answer) in self.answers.iter() {
+            writeln!(f, "{}\t{}\t{:016x}\t{}", day, part, hash, answer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn check_round_trip() {
+    let mut answers = Answers::default();
+    answers.insert(3, Part::Two, 0xabc, "467835".to_string());
+    answers.insert(1, Part::One, u64::MAX, "142".to_string());
+
+    let text = answers.to_string();
+    assert_eq!(
+        text,
+        "# day\tpart\tinput hash\tanswer\n1\t1\tffffffffffffffff\t142\n3\t2\t0000000000000abc\t467835\n"
+    );
+    assert_eq!(Answers::parse(&text), Ok(answers));
+    assert_eq!(Answers::parse("1\t1\tabc"), Err(1));
+}
+
+#[test]
+fn check_input_hash() {
+    assert_eq!(input_hash(""), 0xcbf29ce484222325);
+    assert_eq!(input_hash("ab\r\ncd\n"), input_hash("ab\ncd"));
+    assert_ne!(input_hash("ab\ncd"), input_hash("ab\ncc"));
+}
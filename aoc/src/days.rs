@@ -0,0 +1,25 @@
+use common::{solve, Solver};
+
+pub const DAYS: &[(u32, Solver)] = &[
+    (1, solve::<day_1::Day1>),
+    (2, solve::<day_2::Day2>),
+    (3, solve::<day_3::Day3>),
+    (4, solve::<day_4::Day4>),
+    (5, solve::<day_5::Day5>),
+    (6, solve::<day_6::Day6>),
+    (7, solve::<day_7::Day7>),
+    (8, solve::<day_8::Day8>),
+    (9, solve::<day_9::Day9>),
+    (10, solve::<day_10::Day10>),
+    (11, solve::<day_11::Day11>),
+    (12, solve::<day_12::Day12>),
+    (13, solve::<day_13::Day13>),
+    (14, solve::<day_14::Day14>),
+    (15, solve::<day_15::Day15>),
+    (16, solve::<day_16::Day16>),
+    (17, solve::<day_17::Day17>),
+];
+
+pub fn solver(day: u32) -> Option<Solver> {
+    DAYS.iter().find(|(d, _)| *d == day).map(|(_, s)| *s)
+}
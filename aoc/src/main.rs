@@ -0,0 +1,207 @@
+use std::{path::PathBuf, process::ExitCode, str::FromStr};
+
+use answers::Answers;
+use clap::{Parser, Subcommand};
+use common::{input::InputError, Part};
+
+mod answers;
+mod days;
+
+#[derive(Parser)]
+#[command(about = "Runs the advent of code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs a day, or every day, printing `day<TAB>part<TAB>answer` lines
+    Run {
+        /// Day to run, or `all`
+        #[arg(default_value = "all")]
+        day: DaySelection,
+        /// Only run this part
+        #[arg(long)]
+        part: Option<Part>,
+        /// Input file, or `-` for stdin. Defaults to `inputs/day_N.txt`
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Checks answers for the real inputs against those recorded for the
+    /// same input, recording any which haven't been seen before
+    Verify {
+        /// Day to verify, or `all`
+        #[arg(default_value = "all")]
+        day: DaySelection,
+        /// Replace recorded answers which have changed instead of failing
+        #[arg(long)]
+        update: bool,
+        /// Answers file. Defaults to `inputs/answers.tsv`
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy)]
+enum DaySelection {
+    All,
+    Day(u32),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+
+        let day = s.parse().map_err(|_| format!("'{}' isn't a day", s))?;
+        if days::solver(day).is_none() {
+            return Err(format!("day {} isn't solved", day));
+        }
+
+        Ok(DaySelection::Day(day))
+    }
+}
+
+fn run(day: u32, parts: &[Part], input: Result<String, InputError>) -> bool {
+    let solver = days::solver(day).expect("day should have been validated");
+
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let mut success = true;
+    for &part in parts {
+        match solver(part, &input) {
+            Ok(answer) => println!("{}\t{}\t{}", day, part, answer),
+            Err(e) => {
+                eprintln!("day {} part {} failed: {}", day, part, e);
+                success = false;
+            }
+        }
+    }
+
+    success
+}
+
+fn verify(day: u32, required: bool, answers: &mut Answers, update: bool) -> bool {
+    let solver = days::solver(day).expect("day should have been validated");
+
+    let input = match common::input::read_conventional(day) {
+        Ok(input) => input,
+        Err(InputError::Missing { .. }) if !required => {
+            eprintln!("day {} has no input, skipping", day);
+            return true;
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let hash = answers::input_hash(&input);
+
+    let mut success = true;
+    for part in Part::ALL {
+        let answer = match solver(part, &input) {
+            Ok(answer) => answer,
+            Err(e) => {
+                eprintln!("day {} part {} failed: {}", day, part, e);
+                success = false;
+                continue;
+            }
+        };
+
+        match answers.get(day, part, hash) {
+            Some(expected) if expected == answer => {
+                println!("{}\t{}\t{}\tok", day, part, answer)
+            }
+            Some(expected) if update => {
+                println!("{}\t{}\t{}\tupdated, was {}", day, part, answer, expected);
+                answers.insert(day, part, hash, answer);
+            }
+            Some(expected) => {
+                eprintln!(
+                    "day {} part {} changed: expected {}, got {}",
+                    day, part, expected, answer
+                );
+                success = false;
+            }
+            None => {
+                println!("{}\t{}\t{}\trecorded", day, part, answer);
+                answers.insert(day, part, hash, answer);
+            }
+        }
+    }
+
+    success
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let success = match cli.command {
+        Command::Run { day, part, input } => {
+            let parts = match part {
+                Some(part) => vec![part],
+                None => Part::ALL.to_vec(),
+            };
+
+            match day {
+                DaySelection::Day(day) => {
+                    run(day, &parts, common::input::read(day, input.as_deref()))
+                }
+                DaySelection::All => {
+                    if input.is_some() {
+                        eprintln!("--input can only be used when running a single day");
+                        return ExitCode::FAILURE;
+                    }
+
+                    days::DAYS.iter().fold(true, |success, &(day, _)| {
+                        run(day, &parts, common::input::read_conventional(day)) && success
+                    })
+                }
+            }
+        }
+        Command::Verify {
+            day,
+            update,
+            answers: path,
+        } => {
+            let path = path.unwrap_or_else(Answers::default_path);
+            let mut answers = match Answers::load(&path) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let success = match day {
+                DaySelection::Day(day) => verify(day, true, &mut answers, update),
+                DaySelection::All => days::DAYS.iter().fold(true, |success, &(day, _)| {
+                    verify(day, false, &mut answers, update) && success
+                }),
+            };
+
+            if let Err(e) = answers.save(&path) {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+
+            success
+        }
+    };
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    fmt::Display,
+    io::{IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug)]
+pub enum InputError {
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Stdin(std::io::Error),
+    Missing {
+        day: u32,
+        searched: Vec<PathBuf>,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Io { path, error } => {
+                write!(f, "couldn't read input {}: {}", path.display(), error)
+            }
+            InputError::Stdin(error) => write!(f, "couldn't read input from stdin: {}", error),
+            InputError::Missing { day, searched } => {
+                let searched: Vec<_> = searched.iter().map(|x| x.display().to_string()).collect();
+                write!(
+                    f,
+                    "no input found for day {}, looked in {} (pass a path, or '-' to read from stdin)",
+                    day,
+                    searched.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The `inputs` directories searched for puzzle inputs: one relative to the
+/// current directory and one in the workspace root.
+pub fn input_dirs() -> Vec<PathBuf> {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    let mut dirs = vec![PathBuf::from("inputs")];
+    if let Ok(workspace) = workspace.canonicalize() {
+        dirs.push(workspace.join("inputs"));
+    }
+
+    dirs
+}
+
+/// Where the input for `day` lives by convention, in each of the
+/// [`input_dirs`].
+pub fn conventional_paths(day: u32) -> Vec<PathBuf> {
+    let file = format!("day_{}.txt", day);
+
+    input_dirs().into_iter().map(|x| x.join(&file)).collect()
+}
+
+fn read_path(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|error| InputError::Io {
+        path: path.to_owned(),
+        error,
+    })
+}
+
+fn read_stdin() -> Result<String, InputError> {
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .map_err(InputError::Stdin)?;
+    Ok(input)
+}
+
+/// Reads the input for `day` from the conventional location only.
+pub fn read_conventional(day: u32) -> Result<String, InputError> {
+    let searched = conventional_paths(day);
+    match searched.iter().find(|x| x.is_file()) {
+        Some(path) => read_path(path),
+        None => Err(InputError::Missing { day, searched }),
+    }
+}
+
+/// Reads the input for `day` from `path` if given (`-` meaning stdin),
+/// otherwise from the conventional location, otherwise from stdin if it
+/// isn't a terminal.
+pub fn read(day: u32, path: Option<&str>) -> Result<String, InputError> {
+    match path {
+        Some("-") => return read_stdin(),
+        Some(path) => return read_path(Path::new(path)),
+        None => {}
+    }
+
+    match read_conventional(day) {
+        Err(InputError::Missing { .. }) if !std::io::stdin().is_terminal() => read_stdin(),
+        result => result,
+    }
+}
+
+/// Reads the input for `day` using the first command line argument as the
+/// path, exiting with an error message if it can't be found.
+pub fn read_or_exit(day: u32) -> &'static str {
+    let path = std::env::args().nth(1);
+
+    match read(day, path.as_deref()) {
+        Ok(input) => input.leak(),
+        Err(e) => crate::exit(e),
+    }
+}
@@ -0,0 +1,45 @@
+use std::{fmt::Display, str::FromStr};
+
+pub mod input;
+mod parse;
+mod reference;
+mod solution;
+
+pub use parse::{normalise, ParseError};
+#[doc(hidden)]
+pub use reference::expected;
+pub use solution::{exit, main, solve, Error, Solution};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err("part must be 1 or 2"),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Type erased [`solve`], so the days can be stored together.
+pub type Solver = fn(Part, &str) -> Result<String, Error>;
@@ -0,0 +1,146 @@
+use std::{borrow::Cow, fmt::Display};
+
+/// An error from parsing a puzzle input, pointing at the offending text.
+///
+/// Lines and columns count from 1. Parsers which only see part of the input
+/// create the error relative to that part, and their callers move it into
+/// place with [`ParseError::within`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u32>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+fn offset_in(source: &str, fragment: &str) -> Option<usize> {
+    let source_start = source.as_ptr() as usize;
+    let fragment_start = fragment.as_ptr() as usize;
+
+    if fragment_start >= source_start
+        && fragment_start + fragment.len() <= source_start + source.len()
+    {
+        Some(fragment_start - source_start)
+    } else {
+        None
+    }
+}
+
+fn position(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map(|x| x + 1).unwrap_or(0);
+
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+impl ParseError {
+    /// An error about the whole of `text`.
+    pub fn new(text: &str, message: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line: 1,
+            column: 1,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// An error about `fragment`, which must be a slice of `source`, located
+    /// relative to the start of `source`.
+    pub fn at(source: &str, fragment: &str, message: impl Into<String>) -> Self {
+        Self::new(fragment, message).within(source, fragment)
+    }
+
+    /// Moves an error which was made relative to `fragment` so it is
+    /// relative to `source` instead.
+    pub fn within(mut self, source: &str, fragment: &str) -> Self {
+        let Some(offset) = offset_in(source, fragment) else {
+            return self;
+        };
+
+        let (line, column) = position(source, offset);
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+
+        self
+    }
+
+    pub fn for_day(mut self, day: u32) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {} ({:?})",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Converts `\r\n` line endings to `\n` and strips trailing whitespace from
+/// every line and from the end of the input.
+pub fn normalise(input: &str) -> Cow<'_, str> {
+    let is_normal = !input.contains('\r')
+        && input.trim_end().len() == input.len()
+        && input
+            .lines()
+            .all(|line| line.trim_end().len() == line.len());
+
+    if is_normal {
+        return Cow::Borrowed(input);
+    }
+
+    let mut normalised = input
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n");
+    normalised.truncate(normalised.trim_end().len());
+
+    Cow::Owned(normalised)
+}
+
+#[test]
+fn check_at() {
+    let source = "Time: 7 15\nDistance: 9 a0";
+    let fragment = &source[23..25];
+    let error = ParseError::at(source, fragment, "bad number");
+
+    assert_eq!((error.line, error.column), (2, 13));
+    assert_eq!(error.text, "a0");
+}
+
+#[test]
+fn check_within() {
+    let source = "32T3K 765\nT55J5 684\nKK6X7 28";
+    let line = source.lines().nth(2).unwrap();
+    let error = ParseError::at(line, &line[3..4], "bad card").within(source, line);
+
+    assert_eq!((error.line, error.column), (3, 4));
+    assert_eq!(
+        error.for_day(7).to_string(),
+        "day 7, line 3, column 4: bad card (\"X\")"
+    );
+}
+
+#[test]
+fn check_normalise() {
+    assert!(matches!(normalise("ab\ncd"), Cow::Borrowed("ab\ncd")));
+    assert_eq!(normalise("ab\r\ncd\r\n"), "ab\ncd");
+    assert_eq!(normalise("ab  \n\ncd\t\n\n\n"), "ab\n\ncd");
+}
@@ -0,0 +1,46 @@
+use crate::Error;
+
+/// Property tests that a day's `part_one` and `part_two` agree with the ones
+/// in its `reference` module, on inputs from the given generators. A
+/// reference can return `None` where the real solution gives an error.
+///
+/// Each day's `reference` and `generate` modules are always built for its
+/// tests, and otherwise only with its `reference` feature, which brings in
+/// proptest. The tests go in a module per part.
+///
+/// ```ignore
+/// common::reference_tests! {
+///     Day12;
+///     part_one: input(6, 12);
+///     part_two: input(2, 2), cases = 64;
+/// }
+/// ```
+#[macro_export]
+macro_rules! reference_tests {
+    ($day:ty; $($part:ident: $input:expr $(, cases = $cases:expr)?;)+) => {
+        $(
+            #[cfg(test)]
+            mod $part {
+                use super::*;
+
+                ::proptest::proptest! {
+                    $(#![proptest_config(::proptest::test_runner::Config::with_cases($cases))])?
+
+                    #[test]
+                    fn matches_reference(input in $input) {
+                        let day = <$day as $crate::Solution>::parse(&input).unwrap();
+                        let answer = $crate::Solution::$part(&day);
+                        let expected = $crate::expected(&answer, reference::$part(&day));
+                        ::proptest::prop_assert_eq!(answer.ok(), expected);
+                    }
+                }
+            }
+        )+
+    };
+}
+
+/// What the reference says `answer` should be, for [`reference_tests`].
+#[doc(hidden)]
+pub fn expected<T>(_answer: &Result<T, Error>, reference: impl Into<Option<T>>) -> Option<T> {
+    reference.into()
+}
@@ -0,0 +1,86 @@
+use std::fmt::Display;
+
+use crate::{normalise, ParseError, Part};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    NoSolution(&'static str),
+}
+
+impl From<ParseError> for Error {
+    fn from(value: ParseError) -> Self {
+        Error::Parse(value)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "couldn't parse input: {}", e),
+            Error::NoSolution(message) => write!(f, "no solution: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A day's puzzle, parsed once from the input and then able to answer
+/// either part.
+pub trait Solution: Sized {
+    const DAY: u32;
+
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    /// Parses the puzzle input. Input given to [`solve`] has already been
+    /// through [`normalise`].
+    fn parse(input: &str) -> Result<Self, ParseError>;
+
+    fn part_one(&self) -> Result<Self::PartOne, Error>;
+    fn part_two(&self) -> Result<Self::PartTwo, Error>;
+
+    fn solve_part_one(input: &str) -> Result<Self::PartOne, Error> {
+        parse::<Self>(input)?.part_one()
+    }
+
+    fn solve_part_two(input: &str) -> Result<Self::PartTwo, Error> {
+        parse::<Self>(input)?.part_two()
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<S, Error> {
+    S::parse(&normalise(input)).map_err(|e| Error::Parse(e.for_day(S::DAY)))
+}
+
+/// Parses the input and answers the given part, formatting the answer.
+pub fn solve<S: Solution>(part: Part, input: &str) -> Result<String, Error> {
+    let solution = parse::<S>(input)?;
+
+    match part {
+        Part::One => solution.part_one().map(|x| x.to_string()),
+        Part::Two => solution.part_two().map(|x| x.to_string()),
+    }
+}
+
+/// Prints `message` as an error and exits, for the days' binaries.
+pub fn exit(message: impl std::fmt::Display) -> ! {
+    eprintln!("error: {}", message);
+    std::process::exit(1);
+}
+
+/// Entry point for a day's binary, printing the answers to both parts.
+pub fn main<S: Solution>() {
+    let input = crate::input::read_or_exit(S::DAY);
+
+    let solution = parse::<S>(input).unwrap_or_else(|e| exit(e));
+
+    match solution.part_one() {
+        Ok(answer) => println!("part 1: {}", answer),
+        Err(e) => eprintln!("part 1: {}", e),
+    }
+    match solution.part_two() {
+        Ok(answer) => println!("part 2: {}", answer),
+        Err(e) => eprintln!("part 2: {}", e),
+    }
+}
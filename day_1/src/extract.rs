@@ -0,0 +1,103 @@
+use std::{fmt::Display, io::BufRead};
+
+use crate::Vocabulary;
+
+/// Something wrong with a line which doesn't stop the rest of the document
+/// being read. Lines are numbered from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Warning {
+    pub line: usize,
+    pub message: &'static str,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// The calibration of a whole document with one vocabulary.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Calibration {
+    pub sum: u64,
+    pub warnings: Vec<Warning>,
+}
+
+/// Reads a calibration document from `reader` a line at a time, so it never
+/// has to fit in memory, and calibrates it with each of `vocabularies` in
+/// the same pass. `on_line` is given each line number along with its value
+/// for each vocabulary, if it has one.
+///
+/// Lines without any digits add nothing to the sum and are reported as
+/// warnings. Blank lines are skipped.
+pub fn calibrate(
+    mut reader: impl BufRead,
+    vocabularies: &[&Vocabulary],
+    mut on_line: impl FnMut(usize, &[Option<u32>]),
+) -> std::io::Result<Vec<Calibration>> {
+    let mut calibrations = vec![Calibration::default(); vocabularies.len()];
+    let mut values = vec![None; vocabularies.len()];
+    let mut buffer = vec![];
+
+    for line in 1.. {
+        buffer.clear();
+        if reader.read_until(b'\n', &mut buffer)? == 0 {
+            break;
+        }
+        if buffer.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+
+        for ((vocabulary, calibration), value) in vocabularies
+            .iter()
+            .zip(calibrations.iter_mut())
+            .zip(values.iter_mut())
+        {
+            *value = vocabulary
+                .first_and_last(&buffer)
+                .map(|(first, last)| first * 10 + last);
+
+            match value {
+                Some(value) => calibration.sum += *value as u64,
+                None => calibration.warnings.push(Warning {
+                    line,
+                    message: "no digits",
+                }),
+            }
+        }
+
+        on_line(line, &values);
+    }
+
+    Ok(calibrations)
+}
+
+#[test]
+fn check_calibrate() {
+    let input = "two1nine\r\n\nabc\nxtwone3four\n";
+    let (digits, english) = (Vocabulary::digits(), Vocabulary::english());
+
+    let mut lines = vec![];
+    let calibrations = calibrate(input.as_bytes(), &[&digits, &english], |line, values| {
+        lines.push((line, values.to_vec()))
+    })
+    .unwrap();
+
+    assert_eq!(
+        lines,
+        vec![
+            (1, vec![Some(11), Some(29)]),
+            (3, vec![None, None]),
+            (4, vec![Some(33), Some(24)]),
+        ]
+    );
+    assert_eq!(calibrations[0].sum, 44);
+    assert_eq!(calibrations[1].sum, 53);
+    assert_eq!(
+        calibrations[1].warnings,
+        vec![Warning {
+            line: 3,
+            message: "no digits"
+        }]
+    );
+}
@@ -0,0 +1,40 @@
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day1};
+
+/// Bits of lines, with words which share letters so that digits overlap,
+/// and letters which only make part of a word.
+const PIECES: &[&str] = &[
+    "1",
+    "2",
+    "7",
+    "0",
+    "one",
+    "two",
+    "three",
+    "eight",
+    "nine",
+    "zero",
+    "twone",
+    "oneight",
+    "eighthree",
+    "e",
+    "n",
+    "o",
+    "t",
+    "x",
+];
+
+pub fn input() -> impl Strategy<Value = String> {
+    let line = prop::collection::vec(prop::sample::select(PIECES), 0..=8)
+        .prop_map(|pieces| pieces.concat());
+
+    prop::collection::vec(line, 1..=5).prop_map(|lines| lines.join("\n"))
+}
+
+common::reference_tests! {
+    Day1;
+    part_one: input();
+    part_two: input();
+}
@@ -0,0 +1,94 @@
+use common::{Error, ParseError, Solution};
+
+mod extract;
+mod vocabulary;
+
+pub use extract::{calibrate, Calibration, Warning};
+pub use vocabulary::Vocabulary;
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+static TEST_INPUT: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+#[cfg(test)]
+static TEST_2_INPUT: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+#[test]
+fn check_first_task() {
+    assert_eq!(Day1::solve_part_one(TEST_INPUT), Ok(142));
+}
+
+#[test]
+fn check_second_task() {
+    assert_eq!(Day1::solve_part_two(TEST_2_INPUT), Ok(281));
+}
+
+#[test]
+fn check_other_vocabularies() {
+    let day = Day1::parse("zweieins\nsieben3\nfünfacht").unwrap();
+    assert_eq!(day.calibrate(&Vocabulary::german()), Ok(21 + 73 + 58));
+
+    let day = Day1::parse("achtwee\nzesevenegen").unwrap();
+    assert_eq!(day.calibrate(&Vocabulary::dutch()), Ok(82 + 69));
+
+    let day = Day1::parse("XIV\nVIII").unwrap();
+    assert_eq!(day.calibrate(&Vocabulary::roman()), Ok(44 + 88));
+    assert_eq!(
+        day.calibrate(&Vocabulary::english()),
+        Err(Error::NoSolution("a line has no digits"))
+    );
+}
+
+pub struct Day1 {
+    input: String,
+}
+
+impl Day1 {
+    /// The sum of the calibration values, reading digits with `vocabulary`.
+    /// Unlike [`calibrate`], every line needs to have a digit.
+    pub fn calibrate(&self, vocabulary: &Vocabulary) -> Result<u64, Error> {
+        let calibration = calibrate(self.input.as_bytes(), &[vocabulary], |_, _| {})
+            .expect("reading from memory can't fail")
+            .remove(0);
+
+        if !calibration.warnings.is_empty() {
+            return Err(Error::NoSolution("a line has no digits"));
+        }
+
+        Ok(calibration.sum)
+    }
+}
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day1 {
+            input: input.to_string(),
+        })
+    }
+
+    fn part_one(&self) -> Result<u64, Error> {
+        self.calibrate(&Vocabulary::digits())
+    }
+
+    fn part_two(&self) -> Result<u64, Error> {
+        self.calibrate(&Vocabulary::english())
+    }
+}
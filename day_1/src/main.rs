@@ -1,76 +1,93 @@
-const INPUT: &str = include_str!("input.txt");
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, IsTerminal},
+};
 
-fn digits(s: &str) -> impl Iterator<Item = u32> + '_ {
-    s.chars().filter_map(|x| x.to_digit(10))
-}
-
-fn first_task() {
-    let x: u32 = INPUT
-        .lines()
-        .map(|line| {
-            let first = digits(line).next().unwrap();
-            let last = digits(line).last().unwrap();
-
-            first * 10 + last
-        })
-        .sum();
+use clap::Parser;
+use common::{exit, input::InputError, Solution};
+use day_1::{Day1, Vocabulary};
 
-    dbg!(x);
+/// Solves day 1. Unlike the other days the input is streamed rather than
+/// read up front, so it can be any size.
+#[derive(Parser)]
+struct Args {
+    /// Input file, or `-` for stdin. Defaults to `inputs/day_1.txt`
+    input: Option<String>,
+    /// Vocabulary for part 2: one of the built in ones, or a file with a
+    /// table of `word digit` lines
+    #[arg(long, default_value = "english", value_parser = load_vocabulary)]
+    vocabulary: Vocabulary,
+    /// Print `line<TAB>part 1<TAB>part 2` for every line as it is read
+    #[arg(long)]
+    values: bool,
 }
 
-const NUMBERS: &[&str] = &[
-    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
-];
+fn main() {
+    let args = Args::parse();
 
-fn find_first_number(mut s: &str) -> u32 {
-    while s.len() != 0 {
-        if s.as_bytes().first().unwrap().is_ascii_digit() {
-            let digit = (*s.as_bytes().first().unwrap() - b"0"[0]) as u32;
-            return digit;
-        }
-        for (num, number_str) in NUMBERS.iter().enumerate() {
-            if s.starts_with(number_str) {
-                return num as u32 + 1;
+    let calibrations = day_1::calibrate(
+        open(args.input.as_deref()),
+        &[&Vocabulary::digits(), &args.vocabulary],
+        |line, values| {
+            if args.values {
+                let values: Vec<_> = values
+                    .iter()
+                    .map(|x| x.map_or("-".to_string(), |x| x.to_string()))
+                    .collect();
+                println!("{}\t{}", line, values.join("\t"));
             }
+        },
+    )
+    .unwrap_or_else(|e| exit(format!("couldn't read input: {}", e)));
+
+    for (part, calibration) in (1..).zip(calibrations) {
+        for warning in calibration.warnings.iter() {
+            eprintln!("part {} warning: {}", part, warning);
         }
-        s = &s[1..];
+        println!("part {}: {}", part, calibration.sum);
     }
-
-    panic!("can't find first!!!")
 }
 
-fn find_last_number(mut s: &str) -> u32 {
-    while s.len() != 0 {
-        if s.as_bytes().last().unwrap().is_ascii_digit() {
-            let digit = (*s.as_bytes().last().unwrap() - b"0"[0]) as u32;
-            return digit;
-        }
-        for (num, number_str) in NUMBERS.iter().enumerate() {
-            if s.ends_with(number_str) {
-                return num as u32 + 1;
-            }
-        }
-        s = &s[..s.len() - 1];
+fn load_vocabulary(name: &str) -> Result<Vocabulary, String> {
+    if let Some(vocabulary) = Vocabulary::named(name) {
+        return Ok(vocabulary);
     }
 
-    panic!("can't find last!!!")
+    match std::fs::read_to_string(name) {
+        Ok(table) => Vocabulary::parse(&table).map_err(|e| e.to_string()),
+        Err(e) => Err(format!(
+            "'{}' isn't one of {} and couldn't be read: {}",
+            name,
+            Vocabulary::NAMES.join(", "),
+            e
+        )),
+    }
 }
 
-fn second_task() {
-    let x: u32 = INPUT
-        .lines()
-        .map(|line| {
-            let first = find_first_number(line);
-            let last = find_last_number(line);
-
-            first * 10 + last
-        })
-        .sum();
-
-    dbg!(x);
-}
+/// Opens `path`, `-` meaning stdin, falling back to the conventional input
+/// and then stdin like [`common::input::read`].
+fn open(path: Option<&str>) -> Box<dyn BufRead> {
+    let path = match path {
+        Some("-") => None,
+        Some(path) => Some(path.into()),
+        None => {
+            let searched = common::input::conventional_paths(Day1::DAY);
+            match searched.iter().find(|x| x.is_file()) {
+                Some(path) => Some(path.clone()),
+                None if std::io::stdin().is_terminal() => exit(InputError::Missing {
+                    day: Day1::DAY,
+                    searched,
+                }),
+                None => None,
+            }
+        }
+    };
 
-fn main() {
-    first_task();
-    second_task();
+    match path {
+        Some(path) => match File::open(&path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(e) => exit(format!("couldn't read input {}: {}", path.display(), e)),
+        },
+        None => Box::new(std::io::stdin().lock()),
+    }
 }
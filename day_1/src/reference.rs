@@ -0,0 +1,41 @@
+use crate::Day1;
+
+const WORDS: &[&str] = &[
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The digit at the start of `text`, if there is one.
+fn digit_at(text: &str, words: bool) -> Option<u64> {
+    let first = text.bytes().next()?;
+    if first.is_ascii_digit() {
+        return Some((first - b'0') as u64);
+    }
+
+    words
+        .then(|| WORDS.iter().zip(1..).find(|(x, _)| text.starts_with(*x)))
+        .flatten()
+        .map(|(_, value)| value)
+}
+
+/// Looks for a digit at every position in each line.
+fn calibrate(day: &Day1, words: bool) -> Option<u64> {
+    day.input
+        .lines()
+        .filter(|x| !x.trim().is_empty())
+        .map(|line| {
+            let digits: Vec<_> = (0..line.len())
+                .filter(|&x| line.is_char_boundary(x))
+                .filter_map(|x| digit_at(&line[x..], words))
+                .collect();
+            Some(digits.first()? * 10 + digits.last()?)
+        })
+        .sum()
+}
+
+pub fn part_one(day: &Day1) -> Option<u64> {
+    calibrate(day, false)
+}
+
+pub fn part_two(day: &Day1) -> Option<u64> {
+    calibrate(day, true)
+}
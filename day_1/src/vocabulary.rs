@@ -0,0 +1,158 @@
+use std::cmp::Reverse;
+
+use aho_corasick::{AhoCorasick, Match};
+use common::ParseError;
+
+const ENGLISH: &[&str] = &[
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+const DUTCH: &[&str] = &[
+    "een", "twee", "drie", "vier", "vijf", "zes", "zeven", "acht", "negen",
+];
+
+const GERMAN: &[&str] = &[
+    "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+
+const ROMAN: &[&str] = &["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"];
+
+/// The words which spell out digits in a calibration line. Every vocabulary
+/// also knows the digits `0` to `9` themselves.
+#[derive(Clone, Debug)]
+pub struct Vocabulary {
+    matcher: AhoCorasick,
+    values: Vec<u32>,
+}
+
+impl Vocabulary {
+    /// The built in vocabularies which [`Vocabulary::named`] knows about.
+    pub const NAMES: &'static [&'static str] = &["digits", "english", "dutch", "german", "roman"];
+
+    pub fn new<'a>(words: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        let (words, values): (Vec<_>, Vec<_>) = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"]
+            .into_iter()
+            .zip(0..)
+            .chain(words)
+            .unzip();
+
+        Self {
+            matcher: AhoCorasick::new(words).expect("a handful of words fits in any automaton"),
+            values,
+        }
+    }
+
+    pub fn digits() -> Self {
+        Self::new([])
+    }
+
+    /// A vocabulary where the `n`th word spells `n + 1`.
+    fn counting(words: &[&'static str]) -> Self {
+        Self::new(words.iter().copied().zip(1..))
+    }
+
+    pub fn english() -> Self {
+        Self::counting(ENGLISH)
+    }
+
+    pub fn dutch() -> Self {
+        Self::counting(DUTCH)
+    }
+
+    pub fn german() -> Self {
+        Self::counting(GERMAN)
+    }
+
+    /// Upper case Roman numerals from `I` to `IX`.
+    pub fn roman() -> Self {
+        Self::counting(ROMAN)
+    }
+
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "digits" => Some(Self::digits()),
+            "english" => Some(Self::english()),
+            "dutch" => Some(Self::dutch()),
+            "german" => Some(Self::german()),
+            "roman" => Some(Self::roman()),
+            _ => None,
+        }
+    }
+
+    /// Parses a table of `word digit` lines. Blank lines and lines starting
+    /// with `#` are skipped.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut words = vec![];
+
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((word, value)) = line.split_once(char::is_whitespace) else {
+                return Err(ParseError::at(input, line, "expected '<word> <digit>'"));
+            };
+            let value = value.trim();
+            let value = value
+                .parse()
+                .ok()
+                .filter(|&x| x < 10)
+                .ok_or_else(|| ParseError::at(input, value, "expected a digit"))?;
+
+            words.push((word, value));
+        }
+
+        Ok(Self::new(words))
+    }
+
+    /// The first and last digits in `line`, found in a single pass over it.
+    /// Words may overlap, so `eightwo` is an eight followed by a two. When
+    /// several words start (or end) at the same place the longest wins.
+    pub fn first_and_last(&self, line: impl AsRef<[u8]>) -> Option<(u32, u32)> {
+        let first_key = |x: &Match| (x.start(), Reverse(x.len()));
+        let last_key = |x: &Match| (x.end(), x.len());
+
+        let mut matches = self.matcher.find_overlapping_iter(line.as_ref());
+        let first = matches.next()?;
+        let (first, last) = matches.fold((first, first), |(first, last), x| {
+            (
+                if first_key(&x) < first_key(&first) {
+                    x
+                } else {
+                    first
+                },
+                if last_key(&x) > last_key(&last) {
+                    x
+                } else {
+                    last
+                },
+            )
+        });
+
+        Some((self.values[first.pattern()], self.values[last.pattern()]))
+    }
+}
+
+#[test]
+fn check_overlapping() {
+    let english = Vocabulary::english();
+
+    assert_eq!(english.first_and_last("eightwo"), Some((8, 2)));
+    assert_eq!(english.first_and_last("xoneight"), Some((1, 8)));
+    assert_eq!(english.first_and_last("7"), Some((7, 7)));
+    assert_eq!(english.first_and_last("zero"), None);
+}
+
+#[test]
+fn check_parse() {
+    let vocabulary = Vocabulary::parse("# colours\nred 1\n\nredder 2\n  blue\t3").unwrap();
+
+    assert_eq!(vocabulary.first_and_last("redderblue"), Some((2, 3)));
+    assert_eq!(vocabulary.first_and_last("blue0red"), Some((3, 1)));
+
+    let error = Vocabulary::parse("red 1\nblue 10").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 6));
+    let error = Vocabulary::parse("red 1\nblue").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 1));
+}
@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+
+use proptest::{prelude::*, sample::Index};
+
+#[cfg(test)]
+use crate::{reference, Day10};
+
+/// Inclusive column ranges of a shape made of unit squares, one range per
+/// row, where each row overlaps the one above. The edge of such a shape is a
+/// single loop which never touches itself.
+fn shape() -> impl Strategy<Value = Vec<(i32, i32)>> {
+    prop::collection::vec((0..8i32, 0..5i32), 1..=5).prop_map(|rows| {
+        let mut shape: Vec<(i32, i32)> = vec![];
+        for (start, length) in rows {
+            let row = match shape.last() {
+                Some(&(above_start, above_end)) => {
+                    let start = start.min(above_end);
+                    (start, (start + length).max(above_start))
+                }
+                None => (start, start + length),
+            };
+            shape.push(row);
+        }
+
+        shape
+    })
+}
+
+/// The pipes running along the edge of `shape`, with a tile at each corner
+/// of its squares.
+fn pipe_loop(shape: &[(i32, i32)]) -> BTreeMap<(i32, i32), u8> {
+    let contains = |x: i32, y: i32| {
+        usize::try_from(y)
+            .ok()
+            .and_then(|y| shape.get(y))
+            .is_some_and(|&(start, end)| start <= x && x <= end)
+    };
+
+    // north, east, south and west connections of each tile
+    let mut connections: BTreeMap<(i32, i32), [bool; 4]> = BTreeMap::new();
+    for (y, &(start, end)) in shape.iter().enumerate() {
+        let y = y as i32;
+        for x in start..=end {
+            if !contains(x, y - 1) {
+                connections.entry((x, y)).or_default()[1] = true;
+                connections.entry((x + 1, y)).or_default()[3] = true;
+            }
+            if !contains(x, y + 1) {
+                connections.entry((x, y + 1)).or_default()[1] = true;
+                connections.entry((x + 1, y + 1)).or_default()[3] = true;
+            }
+            if !contains(x - 1, y) {
+                connections.entry((x, y)).or_default()[2] = true;
+                connections.entry((x, y + 1)).or_default()[0] = true;
+            }
+            if !contains(x + 1, y) {
+                connections.entry((x + 1, y)).or_default()[2] = true;
+                connections.entry((x + 1, y + 1)).or_default()[0] = true;
+            }
+        }
+    }
+
+    connections
+        .into_iter()
+        .map(|(position, connections)| {
+            let pipe = match connections {
+                [true, false, true, false] => b'|',
+                [false, true, false, true] => b'-',
+                [true, true, false, false] => b'L',
+                [true, false, false, true] => b'J',
+                [false, false, true, true] => b'7',
+                [false, true, true, false] => b'F',
+                _ => unreachable!("the loop touches itself"),
+            };
+            (position, pipe)
+        })
+        .collect()
+}
+
+/// A single pipe loop with the start somewhere on it, padded out with junk
+/// pipe which never connects to the start.
+pub fn input() -> impl Strategy<Value = String> {
+    (
+        shape(),
+        (0..3i32, 0..3i32, 0..3i32, 0..3i32),
+        any::<Index>(),
+    )
+        .prop_flat_map(|(shape, (left, top, right, bottom), start)| {
+            let pipes = pipe_loop(&shape);
+            let start = *start.get(&pipes.keys().collect::<Vec<_>>());
+            let start = (start.0 + left, start.1 + top);
+            let pipes: BTreeMap<_, _> = pipes
+                .into_iter()
+                .map(|((x, y), pipe)| ((x + left, y + top), pipe))
+                .collect();
+
+            let width = shape.iter().map(|x| x.1).max().unwrap() + 2 + left + right;
+            let height = shape.len() as i32 + 1 + top + bottom;
+
+            let junk = prop::sample::select(&b"|-LJ7F..."[..]);
+            prop::collection::vec(junk, (width * height) as usize).prop_map(move |junk| {
+                let mut input = String::new();
+                for y in 0..height {
+                    if y != 0 {
+                        input.push('\n');
+                    }
+                    for x in 0..width {
+                        let next_to_start = (x - start.0).abs() + (y - start.1).abs() == 1;
+                        let tile = match pipes.get(&(x, y)) {
+                            _ if (x, y) == start => b'S',
+                            Some(&pipe) => pipe,
+                            None if next_to_start => b'.',
+                            None => junk[(x + y * width) as usize],
+                        };
+                        input.push(tile as char);
+                    }
+                }
+
+                input
+            })
+        })
+}
+
+common::reference_tests! {
+    Day10;
+    part_one: input();
+    part_two: input();
+}
@@ -0,0 +1,299 @@
+use std::{cell::Cell, collections::HashSet};
+
+use common::{Error, ParseError, Solution};
+use grid::{Direction, Grid, Vector2d};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+static TEST_INPUT_1: &str = "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+
+#[cfg(test)]
+static TEST_INPUT_2: &str = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+#[derive(Clone)]
+struct Map {
+    grid: Grid<u8>,
+    start_symbol: Cell<u8>,
+}
+
+trait Pipe: Sized {
+    fn has_south_connection(self) -> bool;
+    fn has_north_connection(self) -> bool;
+    fn has_east_connection(self) -> bool;
+    fn has_west_connection(self) -> bool;
+
+    fn has_connection(self, direction: Direction) -> bool {
+        match direction {
+            Direction::North => self.has_north_connection(),
+            Direction::East => self.has_east_connection(),
+            Direction::South => self.has_south_connection(),
+            Direction::West => self.has_west_connection(),
+        }
+    }
+}
+
+const PIPE_SYMBOLS: &[u8] = b"|7FLJ-";
+
+impl Pipe for u8 {
+    fn has_south_connection(self) -> bool {
+        matches!(self, b'|' | b'7' | b'F')
+    }
+
+    fn has_north_connection(self) -> bool {
+        matches!(self, b'|' | b'L' | b'J')
+    }
+
+    fn has_east_connection(self) -> bool {
+        matches!(self, b'-' | b'L' | b'F')
+    }
+
+    fn has_west_connection(self) -> bool {
+        matches!(self, b'-' | b'J' | b'7')
+    }
+}
+
+/// The text of the cell at `(x, y)`, for pointing parse errors at it.
+fn cell(input: &str, Vector2d(x, y): Vector2d) -> &str {
+    let line = input.lines().nth(y as usize).unwrap_or_default();
+    &line[x as usize..x as usize + 1]
+}
+
+impl Map {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, |c| match c {
+            '|' | '-' | 'L' | 'J' | '7' | 'F' | '.' | 'S' => Ok(c as u8),
+            _ => Err("not a pipe"),
+        })?;
+
+        let mut starts = grid
+            .positions()
+            .filter(|&(x, y)| grid.get_or(x, y, b'.') == b'S');
+        if starts.next().is_none() {
+            return Err(ParseError::at(input, &input[input.len()..], "no start"));
+        }
+        if let Some(start) = starts.next() {
+            return Err(ParseError::at(
+                input,
+                cell(input, start.into()),
+                "expected exactly one start",
+            ));
+        }
+
+        let map = Self {
+            grid,
+            start_symbol: Cell::new(b'.'),
+        };
+
+        let start = map.find_start();
+        let connections = Direction::all()
+            .filter(|&d| map.get(start + d.to_vector()).has_connection(d.opposite()))
+            .count();
+        if connections != 2 {
+            return Err(ParseError::at(
+                input,
+                cell(input, start),
+                "expected the start to connect to exactly two pipes",
+            ));
+        }
+
+        Ok(map)
+    }
+
+    fn get(&self, Vector2d(x, y): Vector2d) -> u8 {
+        let s = self.grid.get_or(x, y, b'.');
+        if s == b'S' && self.start_symbol.get() != b'.' {
+            self.start_symbol.get()
+        } else {
+            s
+        }
+    }
+
+    fn find_start(&self) -> Vector2d {
+        self.grid
+            .position(|&x| x == b'S')
+            .expect("start is checked when parsing")
+            .into()
+    }
+
+    fn starting_connections(&self, position: Vector2d) -> [Direction; 2] {
+        let connections: [Direction; 2] = Direction::all()
+            .filter(|&d| {
+                self.get(position + d.to_vector())
+                    .has_connection(d.opposite())
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+
+        let symbol = PIPE_SYMBOLS
+            .iter()
+            .find(|x| connections.iter().all(|&d| x.has_connection(d)))
+            .unwrap();
+
+        self.start_symbol.set(*symbol);
+
+        connections
+    }
+
+    /// Which way the pipe at `position` leads after coming in going `from`,
+    /// if it connects back that way at all.
+    fn connecting_to(&self, position: Vector2d, from: Direction) -> Option<Direction> {
+        let p = self.get(position);
+        if !p.has_connection(from.opposite()) {
+            return None;
+        }
+
+        Direction::all().find(|&d| p.has_connection(d) && d != from.opposite())
+    }
+
+    fn make_pipe_set(&self) -> Result<HashSet<Vector2d>, Error> {
+        let start = self.find_start();
+
+        let mut visited = HashSet::new();
+
+        visited.insert(start);
+
+        let start_directions = self.starting_connections(start);
+
+        let mut current_position = start;
+        let mut current_direction = start_directions[0];
+
+        loop {
+            let next_position = current_position + current_direction.to_vector();
+
+            if !visited.insert(next_position) {
+                break;
+            }
+
+            let next_direction =
+                self.connecting_to(next_position, current_direction)
+                    .ok_or(Error::NoSolution(
+                        "the pipes from the start don't form a loop",
+                    ))?;
+
+            current_direction = next_direction;
+            current_position = next_position;
+        }
+
+        Ok(visited)
+    }
+}
+
+fn first_task(map: Map) -> Result<u64, Error> {
+    Ok(map.make_pipe_set()?.len() as u64 / 2)
+}
+
+#[test]
+fn check_fist_task() {
+    assert_eq!(Day10::solve_part_one(TEST_INPUT_1), Ok(8));
+}
+
+fn second_task(map: Map) -> Result<u64, Error> {
+    let s = map.make_pipe_set()?;
+
+    let mut inside_count = 0;
+
+    for y in 0..map.grid.height() {
+        let mut inside = false;
+        let mut in_horizontal_pipe_section = None;
+
+        for x in 0..map.grid.width() {
+            let is_pipe = s.contains(&Vector2d(x, y));
+            if !is_pipe && inside {
+                inside_count += 1;
+            }
+
+            if is_pipe {
+                let pipe = map.get(Vector2d(x, y));
+                if let Some(hor) = in_horizontal_pipe_section {
+                    if hor == Direction::North && pipe.has_south_connection()
+                        || hor == Direction::South && pipe.has_north_connection()
+                    {
+                        inside = !inside;
+                    }
+                    if pipe.has_north_connection() || pipe.has_south_connection() {
+                        in_horizontal_pipe_section = None;
+                    }
+                } else if pipe.has_north_connection() && pipe.has_south_connection() {
+                    inside = !inside;
+                } else {
+                    if pipe.has_north_connection() {
+                        in_horizontal_pipe_section = Some(Direction::North);
+                    }
+                    if pipe.has_south_connection() {
+                        in_horizontal_pipe_section = Some(Direction::South);
+                    }
+                }
+            }
+        }
+
+        if inside || in_horizontal_pipe_section.is_some() {
+            return Err(Error::NoSolution("the loop doesn't close on every row"));
+        }
+    }
+
+    Ok(inside_count)
+}
+
+#[test]
+fn check_second_task() {
+    assert_eq!(Day10::solve_part_two(TEST_INPUT_2), Ok(8));
+}
+
+#[test]
+fn check_bad_loops() {
+    assert!(Day10::parse("S").is_err());
+    assert!(Day10::parse(".|.\n-S-\n...").is_err());
+
+    let error = Day10::parse("S-7.\n|.|.\nL-JS").err().unwrap();
+    assert_eq!((error.line, error.column), (3, 4));
+    assert_eq!(error.text, "S");
+    let error = Day10::parse("...\n.S-\n...").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 2));
+    assert_eq!(error.text, "S");
+    assert_eq!(Day10::parse("F-7\nL-J").err().unwrap().message, "no start");
+    assert!(Day10::solve_part_one("S7\nL.").is_err());
+    assert!(Day10::solve_part_two("S7.\n|L-\nL-.").is_err());
+}
+
+pub struct Day10 {
+    map: Map,
+}
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day10 {
+            map: Map::new(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u64, Error> {
+        first_task(self.map.clone())
+    }
+
+    fn part_two(&self) -> Result<u64, Error> {
+        second_task(self.map.clone())
+    }
+}
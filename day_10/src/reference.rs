@@ -0,0 +1,92 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use grid::Grid;
+
+use crate::Day10;
+
+fn connections(tile: u8) -> &'static [(i32, i32)] {
+    match tile {
+        b'|' => &[(0, -1), (0, 1)],
+        b'-' => &[(-1, 0), (1, 0)],
+        b'L' => &[(0, -1), (1, 0)],
+        b'J' => &[(0, -1), (-1, 0)],
+        b'7' => &[(0, 1), (-1, 0)],
+        b'F' => &[(0, 1), (1, 0)],
+        _ => &[],
+    }
+}
+
+/// The distance along the loop from the start to every tile on it, found
+/// with a breadth first search through pipes which connect both ways.
+fn loop_distances(day: &Day10) -> HashMap<(i32, i32), u64> {
+    let grid = &day.map.grid;
+    let start = grid.position(|&x| x == b'S').unwrap();
+    let links = |(x, y): (i32, i32)| -> Vec<(i32, i32)> {
+        let tile = grid[(x, y)];
+        [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .filter(|&(dx, dy)| tile == b'S' || connections(tile).contains(&(dx, dy)))
+            .map(|(dx, dy)| (x + dx, y + dy))
+            .filter(|&(nx, ny)| {
+                grid.get(nx, ny).is_some_and(|&other| {
+                    other == b'S' || connections(other).contains(&(x - nx, y - ny))
+                })
+            })
+            .collect()
+    };
+
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(position) = queue.pop_front() {
+        let distance = distances[&position];
+        for next in links(position) {
+            distances.entry(next).or_insert_with(|| {
+                queue.push_back(next);
+                distance + 1
+            });
+        }
+    }
+
+    distances
+}
+
+pub fn part_one(day: &Day10) -> u64 {
+    loop_distances(day).into_values().max().unwrap()
+}
+
+/// Draws the loop at three times the size, with a border around it, and
+/// floods the outside. Any tile whose centre the flood doesn't reach is
+/// enclosed.
+pub fn part_two(day: &Day10) -> u64 {
+    let grid = &day.map.grid;
+    let pipes: HashSet<_> = loop_distances(day).into_keys().collect();
+
+    let mut walls = Grid::from_fn(grid.width() * 3 + 2, grid.height() * 3 + 2, |_, _| false);
+    for &(x, y) in pipes.iter() {
+        let centre = (x * 3 + 2, y * 3 + 2);
+        walls[centre] = true;
+        for &(dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0)].iter() {
+            if pipes.contains(&(x + dx, y + dy))
+                && (grid[(x, y)] == b'S' || connections(grid[(x, y)]).contains(&(dx, dy)))
+                && (grid[(x + dx, y + dy)] == b'S'
+                    || connections(grid[(x + dx, y + dy)]).contains(&(-dx, -dy)))
+            {
+                walls[(centre.0 + dx, centre.1 + dy)] = true;
+            }
+        }
+    }
+
+    let mut outside = HashSet::from([(0, 0)]);
+    let mut queue = vec![(0, 0)];
+    while let Some((x, y)) = queue.pop() {
+        for next in walls.neighbours4(x, y) {
+            if !walls[next] && outside.insert(next) {
+                queue.push(next);
+            }
+        }
+    }
+
+    grid.positions()
+        .filter(|&(x, y)| !pipes.contains(&(x, y)) && !outside.contains(&(x * 3 + 2, y * 3 + 2)))
+        .count() as u64
+}
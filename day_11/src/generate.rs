@@ -0,0 +1,23 @@
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day11};
+
+/// An image with galaxies sparse enough to leave some empty rows and columns.
+pub fn input(max_size: usize) -> impl Strategy<Value = String> {
+    let pixel = prop::sample::select(&['.', '.', '.', '.', '#'][..]);
+
+    (1..=max_size, 1..=max_size).prop_flat_map(move |(width, height)| {
+        prop::collection::vec(
+            prop::collection::vec(pixel.clone(), width).prop_map(String::from_iter),
+            height,
+        )
+        .prop_map(|x| x.join("\n"))
+    })
+}
+
+common::reference_tests! {
+    Day11;
+    part_one: input(12);
+    part_two: input(12);
+}
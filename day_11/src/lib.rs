@@ -0,0 +1,144 @@
+use std::collections::HashSet;
+
+use common::{Error, ParseError, Solution};
+use grid::Grid;
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+static TEST_INPUT: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+#[derive(Clone)]
+struct Map {
+    initial_width: usize,
+    initial_height: usize,
+    galaxies: HashSet<(usize, usize)>,
+}
+
+impl Map {
+    fn construct(input: &str) -> Result<Self, ParseError> {
+        let image = Grid::parse(input, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("expected '.' or '#'"),
+        })?;
+
+        let galaxies = image
+            .positions()
+            .filter(|&(x, y)| image[(x, y)])
+            .map(|(x, y)| (x as usize, y as usize))
+            .collect();
+
+        Ok(Self {
+            initial_width: image.width() as usize,
+            initial_height: image.height() as usize,
+            galaxies,
+        })
+    }
+
+    fn expand_x(&mut self, by: usize) {
+        let mut columns = vec![0; self.initial_width];
+
+        for (x, _) in self.galaxies.iter() {
+            columns[*x] += 1;
+        }
+
+        let a = extended(&columns);
+
+        self.galaxies = self
+            .galaxies
+            .iter()
+            .map(|&(x, y)| (x + a[x] * by, y))
+            .collect();
+    }
+
+    fn expand_y(&mut self, by: usize) {
+        let mut rows = vec![0; self.initial_height];
+
+        for (_, y) in self.galaxies.iter() {
+            rows[*y] += 1;
+        }
+
+        let a = extended(&rows);
+
+        self.galaxies = self
+            .galaxies
+            .iter()
+            .map(|&(x, y)| (x, y + a[y] * by))
+            .collect();
+    }
+}
+
+fn extended(v: &[usize]) -> Vec<usize> {
+    let mut c = 0;
+    v.iter()
+        .map(|&x| {
+            if x == 0 {
+                c += 1
+            }
+            c
+        })
+        .collect()
+}
+
+fn first_task(map: &Map, by: usize) -> i64 {
+    let mut map = map.clone();
+    map.expand_x(by);
+    map.expand_y(by);
+
+    map.galaxies
+        .iter()
+        .flat_map(|&c| map.galaxies.iter().map(move |&e| (e, c)))
+        .map(|(a, b)| (a.0 as i64 - b.0 as i64).abs() + (a.1 as i64 - b.1 as i64).abs())
+        .sum::<i64>()
+        / 2
+}
+
+#[test]
+fn check_first_task() {
+    assert_eq!(Day11::solve_part_one(TEST_INPUT), Ok(374));
+}
+
+#[test]
+fn check_second_task() {
+    let map = Map::construct(TEST_INPUT).unwrap();
+    assert_eq!(first_task(&map, 9), 1030);
+    assert_eq!(first_task(&map, 99), 8410);
+}
+
+pub struct Day11 {
+    map: Map,
+}
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day11 {
+            map: Map::construct(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<i64, Error> {
+        Ok(first_task(&self.map, 1))
+    }
+
+    fn part_two(&self) -> Result<i64, Error> {
+        Ok(first_task(&self.map, 1000000 - 1))
+    }
+}
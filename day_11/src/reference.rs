@@ -0,0 +1,34 @@
+use crate::Day11;
+
+/// Walks between every pair of galaxies, counting each empty row and column
+/// crossed as `empty_size` wide.
+fn distances(day: &Day11, empty_size: i64) -> i64 {
+    let map = &day.map;
+    let galaxies: Vec<_> = map.galaxies.iter().copied().collect();
+    let empty_column = |x: usize| galaxies.iter().all(|g| g.0 != x);
+    let empty_row = |y: usize| galaxies.iter().all(|g| g.1 != y);
+
+    let mut total = 0;
+    for (idx, a) in galaxies.iter().enumerate() {
+        for b in galaxies[idx + 1..].iter() {
+            let columns = a.0.min(b.0)..a.0.max(b.0);
+            let rows = a.1.min(b.1)..a.1.max(b.1);
+            total += columns
+                .map(|x| if empty_column(x) { empty_size } else { 1 })
+                .sum::<i64>();
+            total += rows
+                .map(|y| if empty_row(y) { empty_size } else { 1 })
+                .sum::<i64>();
+        }
+    }
+
+    total
+}
+
+pub fn part_one(day: &Day11) -> i64 {
+    distances(day, 2)
+}
+
+pub fn part_two(day: &Day11) -> i64 {
+    distances(day, 1000000)
+}
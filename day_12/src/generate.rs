@@ -0,0 +1,40 @@
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day12};
+
+/// A row of springs with some hidden behind `?`, followed by the groups of
+/// the row it was made from, so there is always at least one arrangement.
+fn row(max_length: usize) -> impl Strategy<Value = String> {
+    prop::collection::vec((any::<bool>(), any::<bool>()), 1..=max_length)
+        .prop_filter("no damaged springs", |x| {
+            x.iter().any(|&(damaged, _)| damaged)
+        })
+        .prop_map(|springs| {
+            let condition: String = springs
+                .iter()
+                .map(|&(damaged, hidden)| match (damaged, hidden) {
+                    (_, true) => '?',
+                    (true, false) => '#',
+                    (false, false) => '.',
+                })
+                .collect();
+            let groups: Vec<_> = springs
+                .split(|&(damaged, _)| !damaged)
+                .filter(|x| !x.is_empty())
+                .map(|x| x.len().to_string())
+                .collect();
+
+            format!("{} {}", condition, groups.join(","))
+        })
+}
+
+pub fn input(rows: usize, max_length: usize) -> impl Strategy<Value = String> {
+    prop::collection::vec(row(max_length), 1..=rows).prop_map(|x| x.join("\n"))
+}
+
+common::reference_tests! {
+    Day12;
+    part_one: input(6, 12);
+    part_two: input(2, 2), cases = 64;
+}
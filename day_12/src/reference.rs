@@ -0,0 +1,52 @@
+use crate::{Day12, SpringCondition, Springs};
+
+fn groups(condition: &[SpringCondition]) -> Vec<u32> {
+    condition
+        .split(|&x| x != SpringCondition::Damaged)
+        .filter(|x| !x.is_empty())
+        .map(|x| x.len() as u32)
+        .collect()
+}
+
+/// Tries every way of filling in the unknown springs.
+fn count(springs: &Springs) -> u64 {
+    let unknown: Vec<usize> = (0..springs.condition.len())
+        .filter(|&idx| springs.condition[idx] == SpringCondition::Unknown)
+        .collect();
+
+    (0..1_u64 << unknown.len())
+        .filter(|mask| {
+            let mut condition = springs.condition.clone();
+            for (bit, &idx) in unknown.iter().enumerate() {
+                condition[idx] = if mask & (1 << bit) != 0 {
+                    SpringCondition::Damaged
+                } else {
+                    SpringCondition::Operational
+                };
+            }
+
+            groups(&condition) == springs.damaged_springs
+        })
+        .count() as u64
+}
+
+fn unfold(springs: &Springs) -> Springs {
+    let mut condition = springs.condition.clone();
+    for _ in 0..4 {
+        condition.push(SpringCondition::Unknown);
+        condition.extend(&springs.condition);
+    }
+
+    Springs {
+        condition,
+        damaged_springs: springs.damaged_springs.repeat(5),
+    }
+}
+
+pub fn part_one(day: &Day12) -> u64 {
+    day.puzzles.iter().map(count).sum()
+}
+
+pub fn part_two(day: &Day12) -> u64 {
+    day.puzzles.iter().map(unfold).map(|x| count(&x)).sum()
+}
@@ -0,0 +1,53 @@
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day13};
+
+/// Random terrain which is then reflected in a line across the rows or the
+/// columns, and maybe has a smudge added somewhere.
+fn terrain() -> impl Strategy<Value = String> {
+    (2..=7usize, 2..=7usize, any::<bool>())
+        .prop_flat_map(|(width, height, across_rows)| {
+            let cells = prop::collection::vec(prop::collection::vec(any::<bool>(), width), height);
+            let line = 1..if across_rows { height } else { width };
+            let smudge = prop::option::of((0..width, 0..height));
+
+            (cells, Just(across_rows), line, smudge)
+        })
+        .prop_map(|(mut cells, across_rows, line, smudge)| {
+            let (width, height) = (cells[0].len(), cells.len());
+            for y in 0..height {
+                for x in 0..width {
+                    let (from, at) = if across_rows { (y, line) } else { (x, line) };
+                    if from < at || from >= 2 * at {
+                        continue;
+                    }
+                    let mirror = 2 * at - 1 - from;
+                    cells[y][x] = if across_rows {
+                        cells[mirror][x]
+                    } else {
+                        cells[y][mirror]
+                    };
+                }
+            }
+            if let Some((x, y)) = smudge {
+                cells[y][x] = !cells[y][x];
+            }
+
+            let rows: Vec<String> = cells
+                .iter()
+                .map(|row| row.iter().map(|&x| if x { '#' } else { '.' }).collect())
+                .collect();
+            rows.join("\n")
+        })
+}
+
+pub fn input() -> impl Strategy<Value = String> {
+    prop::collection::vec(terrain(), 1..=4).prop_map(|x| x.join("\n\n"))
+}
+
+common::reference_tests! {
+    Day13;
+    part_one: input();
+    part_two: input();
+}
@@ -0,0 +1,149 @@
+use common::{Error, ParseError, Solution};
+use grid::Grid;
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+static TEST_INPUT: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+type Terrain = Grid<Ground>;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Ground {
+    Ash,
+    Rock,
+}
+
+fn parse_terrain(input: &str) -> Result<Terrain, ParseError> {
+    Grid::parse(input, |c| match c {
+        '#' => Ok(Ground::Rock),
+        '.' => Ok(Ground::Ash),
+        _ => Err("bad data in map"),
+    })
+}
+
+fn count_differences<I: Iterator<Item = Ground>>(
+    a: I,
+    b: I,
+    difference_limit: usize,
+) -> Option<usize> {
+    let mut difference_count = 0;
+    for (aa, bb) in a.zip(b) {
+        if aa != bb {
+            difference_count += 1;
+            if difference_count > difference_limit {
+                return None;
+            }
+        }
+    }
+
+    Some(difference_count)
+}
+
+fn find_matching<F, I>(limit: i32, matching_thing: F, difference_count: usize) -> Option<i32>
+where
+    F: Fn(i32) -> I,
+    I: Iterator<Item = Ground>,
+{
+    'outer: for (a, b) in (0..limit - 1).map(|a| (a, a + 1)) {
+        let aa = matching_thing(a);
+        let bb = matching_thing(b);
+
+        if let Some(mut accumulated_difference) = count_differences(aa, bb, difference_count) {
+            for (aaa, bbb) in (0..a).rev().zip(b + 1..limit) {
+                let aa = matching_thing(aaa);
+                let bb = matching_thing(bbb);
+
+                if let Some(additional_difference) =
+                    count_differences(aa, bb, difference_count - accumulated_difference)
+                {
+                    accumulated_difference += additional_difference;
+                } else {
+                    continue 'outer;
+                }
+            }
+
+            if accumulated_difference != difference_count {
+                continue 'outer;
+            }
+
+            return Some(a + 1);
+        }
+    }
+
+    None
+}
+
+fn calculate_mirrors(terrains: &[Terrain], difference_count: usize) -> Result<u64, Error> {
+    terrains
+        .iter()
+        .map(|x| {
+            find_matching(x.height(), |y| x.row(y).iter().copied(), difference_count)
+                .map(|x| x * 100)
+                .or_else(|| find_matching(x.width(), |xx| x.column(xx).copied(), difference_count))
+                .ok_or(Error::NoSolution("terrain has no line of reflection"))
+        })
+        .map(|x| x.map(|x| TryInto::<u64>::try_into(x).unwrap()))
+        .sum()
+}
+
+#[test]
+fn check_first_task() {
+    assert_eq!(Day13::solve_part_one(TEST_INPUT), Ok(405));
+}
+
+#[test]
+fn check_second_task() {
+    assert_eq!(Day13::solve_part_two(TEST_INPUT), Ok(400));
+}
+
+#[test]
+fn check_crlf() {
+    let input = format!("{}\r\n", TEST_INPUT.replace('\n', "\r\n"));
+    assert_eq!(Day13::solve_part_one(&input), Ok(405));
+}
+
+pub struct Day13 {
+    terrains: Vec<Terrain>,
+}
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day13 {
+            terrains: input
+                .split("\n\n")
+                .map(|terrain| parse_terrain(terrain).map_err(|e| e.within(input, terrain)))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u64, Error> {
+        calculate_mirrors(&self.terrains, 0)
+    }
+
+    fn part_two(&self) -> Result<u64, Error> {
+        calculate_mirrors(&self.terrains, 1)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{Day13, Terrain};
+
+/// How many cells differ from their reflection in a line between rows
+/// `line - 1` and `line`.
+fn differences(terrain: &Terrain, line: i32) -> usize {
+    terrain
+        .positions()
+        .filter(|&(_, y)| y < line)
+        .filter(|&(x, y)| {
+            terrain
+                .get(x, 2 * line - 1 - y)
+                .is_some_and(|other| *other != terrain[(x, y)])
+        })
+        .count()
+}
+
+/// Tries every line across the rows, then every line across the columns.
+fn summarise(day: &Day13, smudges: usize) -> Option<u64> {
+    day.terrains
+        .iter()
+        .map(|terrain| {
+            let transposed = terrain.transpose();
+            let row = (1..terrain.height()).find(|&x| differences(terrain, x) == smudges);
+            let column = (1..terrain.width()).find(|&x| differences(&transposed, x) == smudges);
+
+            row.map(|x| x as u64 * 100).or(column.map(|x| x as u64))
+        })
+        .sum()
+}
+
+pub fn part_one(day: &Day13) -> Option<u64> {
+    summarise(day, 0)
+}
+
+pub fn part_two(day: &Day13) -> Option<u64> {
+    summarise(day, 1)
+}
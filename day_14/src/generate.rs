@@ -0,0 +1,26 @@
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day14};
+
+pub fn input() -> impl Strategy<Value = String> {
+    let cell = prop::sample::select(&b"...##OO"[..]);
+
+    (1..=8usize, 1..=8usize)
+        .prop_flat_map(move |(width, height)| {
+            prop::collection::vec(prop::collection::vec(cell.clone(), width), height)
+        })
+        .prop_map(|rows| {
+            let rows: Vec<_> = rows
+                .into_iter()
+                .map(|x| String::from_utf8(x).unwrap())
+                .collect();
+            rows.join("\n")
+        })
+}
+
+common::reference_tests! {
+    Day14;
+    part_one: input();
+    part_two: input();
+}
@@ -0,0 +1,159 @@
+use std::{collections::HashMap, fmt::Display};
+
+use common::{Error, ParseError, Solution};
+use grid::Grid;
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+static TEST_INPUT: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+type Mirror = Grid<Ground>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+enum Ground {
+    Surface,
+    Rock,
+    RoundedRock,
+}
+
+impl Display for Ground {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ground::Surface => '.',
+            Ground::Rock => '#',
+            Ground::RoundedRock => 'O',
+        }
+        .fmt(f)
+    }
+}
+
+fn parse_mirror(input: &str) -> Result<Mirror, ParseError> {
+    Grid::parse(input, |c| match c {
+        '#' => Ok(Ground::Rock),
+        '.' => Ok(Ground::Surface),
+        'O' => Ok(Ground::RoundedRock),
+        _ => Err("bad data in map"),
+    })
+}
+
+fn tilt_vector(mirror: &mut Mirror, vector: (i32, i32)) {
+    fn do_point(mirror: &mut Mirror, x: i32, y: i32, vector: (i32, i32)) {
+        let (mut xx, mut yy) = (x, y);
+        if mirror[(xx, yy)] == Ground::RoundedRock {
+            mirror[(xx, yy)] = Ground::Surface;
+            while mirror.get(xx + vector.0, yy + vector.1) == Some(&Ground::Surface) {
+                (xx, yy) = (xx + vector.0, yy + vector.1);
+            }
+            mirror[(xx, yy)] = Ground::RoundedRock;
+        }
+    }
+
+    if vector.0 < 0 || vector.1 < 0 {
+        for y in 0..mirror.height() {
+            for x in 0..mirror.width() {
+                do_point(mirror, x, y, vector);
+            }
+        }
+    } else {
+        for y in (0..mirror.height()).rev() {
+            for x in (0..mirror.width()).rev() {
+                do_point(mirror, x, y, vector);
+            }
+        }
+    }
+}
+
+fn calculate_load(mirror: &Mirror) -> u64 {
+    (0..mirror.height())
+        .map(|x| {
+            mirror
+                .row(x)
+                .iter()
+                .filter(|&&x| x == Ground::RoundedRock)
+                .count()
+        })
+        .enumerate()
+        .map(|(idx, count)| (mirror.height() as u64 - idx as u64) * count as u64)
+        .sum()
+}
+
+fn first_task(mirror: &Mirror) -> u64 {
+    let mut mirror = mirror.clone();
+
+    tilt_vector(&mut mirror, (0, -1));
+
+    calculate_load(&mirror)
+}
+
+fn second_task(mirror: &Mirror) -> u64 {
+    let mut mirror = mirror.clone();
+
+    let mut cache: HashMap<Mirror, usize> = HashMap::new();
+
+    let repeat_times = 1000000000;
+
+    for idx in 0..repeat_times {
+        if let Some(&last_encounter) = cache.get(&mirror) {
+            let distance_since = idx - last_encounter;
+            if (repeat_times - idx) % distance_since == 0 {
+                break;
+            }
+        } else {
+            cache.insert(mirror.clone(), idx);
+        }
+        tilt_vector(&mut mirror, (0, -1));
+        tilt_vector(&mut mirror, (-1, 0));
+        tilt_vector(&mut mirror, (0, 1));
+        tilt_vector(&mut mirror, (1, 0));
+    }
+
+    calculate_load(&mirror)
+}
+
+#[test]
+fn check_first_task() {
+    assert_eq!(Day14::solve_part_one(TEST_INPUT), Ok(136));
+}
+
+#[test]
+fn check_second_task() {
+    assert_eq!(Day14::solve_part_two(TEST_INPUT), Ok(64));
+}
+
+pub struct Day14 {
+    mirror: Mirror,
+}
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day14 {
+            mirror: parse_mirror(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u64, Error> {
+        Ok(first_task(&self.mirror))
+    }
+
+    fn part_two(&self) -> Result<u64, Error> {
+        Ok(second_task(&self.mirror))
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{Day14, Ground, Mirror};
+
+/// Moves rounded rocks one step at a time until none of them can move.
+fn tilt(mirror: &mut Mirror, (dx, dy): (i32, i32)) {
+    loop {
+        let moving: Vec<_> = mirror
+            .positions()
+            .filter(|&(x, y)| {
+                mirror[(x, y)] == Ground::RoundedRock
+                    && mirror.get(x + dx, y + dy) == Some(&Ground::Surface)
+            })
+            .collect();
+        if moving.is_empty() {
+            break;
+        }
+
+        for (x, y) in moving {
+            mirror[(x, y)] = Ground::Surface;
+            mirror[(x + dx, y + dy)] = Ground::RoundedRock;
+        }
+    }
+}
+
+fn load(mirror: &Mirror) -> u64 {
+    mirror
+        .positions()
+        .filter(|&x| mirror[x] == Ground::RoundedRock)
+        .map(|(_, y)| (mirror.height() - y) as u64)
+        .sum()
+}
+
+pub fn part_one(day: &Day14) -> u64 {
+    let mut mirror = day.mirror.clone();
+    tilt(&mut mirror, (0, -1));
+
+    load(&mirror)
+}
+
+/// Spins until the mirror looks like it did before, and then skips all the
+/// times round the loop that makes.
+pub fn part_two(day: &Day14) -> u64 {
+    let spins = 1_000_000_000;
+    let mut seen = vec![day.mirror.clone()];
+
+    loop {
+        let mut mirror = seen.last().unwrap().clone();
+        for direction in [(0, -1), (-1, 0), (0, 1), (1, 0)] {
+            tilt(&mut mirror, direction);
+        }
+
+        if let Some(start) = seen.iter().position(|x| *x == mirror) {
+            let length = seen.len() - start;
+            return load(&seen[start + (spins - start) % length]);
+        }
+        seen.push(mirror);
+    }
+}
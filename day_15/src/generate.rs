@@ -0,0 +1,27 @@
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day15};
+
+/// A few labels, so that lenses get replaced and removed, including some
+/// which hash to the same box: "rn" and "cm" both go in box 0.
+const LABELS: &[&str] = &["rn", "cm", "qp", "pc", "ot", "ab", "x", "rnx"];
+
+fn step() -> impl Strategy<Value = String> {
+    let label = prop::sample::select(LABELS);
+
+    prop_oneof![
+        (label.clone(), 1..=9u32).prop_map(|(label, x)| format!("{}={}", label, x)),
+        label.prop_map(|x| format!("{}-", x)),
+    ]
+}
+
+pub fn input() -> impl Strategy<Value = String> {
+    prop::collection::vec(step(), 1..=20).prop_map(|x| x.join(","))
+}
+
+common::reference_tests! {
+    Day15;
+    part_one: input();
+    part_two: input();
+}
@@ -0,0 +1,155 @@
+use common::{Error, ParseError, Solution};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+static TEST_INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+fn hash_bytes(b: &[u8]) -> u8 {
+    b.iter()
+        .copied()
+        .fold(0u8, |acc, b| acc.wrapping_add(b).wrapping_mul(17))
+}
+
+fn first_task(steps: &[String]) -> u64 {
+    steps
+        .iter()
+        .map(|s| {
+            s.as_bytes()
+                .iter()
+                .copied()
+                .fold(0u8, |acc, b| acc.wrapping_add(b).wrapping_mul(17))
+        })
+        .map(Into::<u64>::into)
+        .sum()
+}
+
+enum Operation {
+    Add(u32),
+    Remove,
+}
+
+struct Action {
+    label: String,
+    operation: Operation,
+}
+
+fn parse_action(input: &str, step: &str) -> Result<Action, ParseError> {
+    let (label, operation) = {
+        if let Some((label, focal_length)) = step.split_once('=') {
+            let focal_length = focal_length
+                .parse()
+                .map_err(|_| ParseError::at(input, focal_length, "bad focal length"))?;
+            (label, Operation::Add(focal_length))
+        } else {
+            let Some((label, end)) = step.split_once('-') else {
+                return Err(ParseError::at(input, step, "expected '=' or '-'"));
+            };
+            if !end.is_empty() {
+                return Err(ParseError::at(input, end, "unexpected text after '-'"));
+            }
+            (label, Operation::Remove)
+        }
+    };
+    Ok(Action {
+        label: label.to_string(),
+        operation,
+    })
+}
+
+struct Lens<'a> {
+    label: &'a [u8],
+    focal_length: u32,
+}
+
+fn second_task(actions: &[Action]) -> u64 {
+    let mut map = Vec::new();
+    for _ in 0..256 {
+        map.push(Vec::<Lens>::new());
+    }
+
+    for Action { label, operation } in actions {
+        let label = label.as_bytes();
+        let hash = hash_bytes(label) as usize;
+
+        let v = &mut map[hash];
+
+        match *operation {
+            Operation::Add(focal_length) => match v.iter_mut().find(|x| x.label == label) {
+                Some(lens) => lens.focal_length = focal_length,
+                None => v.push(Lens {
+                    label,
+                    focal_length,
+                }),
+            },
+            Operation::Remove => {
+                if let Some(idx) = v.iter_mut().position(|x| x.label == label) {
+                    v.remove(idx);
+                }
+            }
+        }
+    }
+
+    map.iter()
+        .zip(1u64..)
+        .map(|(lens_box, idx)| {
+            lens_box
+                .iter()
+                .zip(1u64..)
+                .map(|(lens, lens_idx)| lens.focal_length as u64 * lens_idx * idx)
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+#[test]
+fn check_first_task() {
+    assert_eq!(Day15::solve_part_one(TEST_INPUT), Ok(1320));
+}
+
+#[test]
+fn check_second_task() {
+    assert_eq!(Day15::solve_part_two(TEST_INPUT), Ok(145));
+}
+
+#[test]
+fn check_trailing_newline() {
+    let input = format!("{}\n", TEST_INPUT);
+    assert_eq!(Day15::parse(&input).map(|x| x.steps.len()), Ok(11));
+    assert_eq!(Day15::solve_part_two(&input), Ok(145));
+}
+
+pub struct Day15 {
+    steps: Vec<String>,
+    actions: Vec<Action>,
+}
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let input = input.trim_end();
+
+        Ok(Day15 {
+            steps: input.split(',').map(String::from).collect(),
+            actions: input
+                .split(',')
+                .map(|step| parse_action(input, step))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u64, Error> {
+        Ok(first_task(&self.steps))
+    }
+
+    fn part_two(&self) -> Result<u64, Error> {
+        Ok(second_task(&self.actions))
+    }
+}
@@ -0,0 +1,41 @@
+use crate::Day15;
+
+fn hash(text: &str) -> u64 {
+    text.bytes().fold(0, |acc, x| (acc + x as u64) * 17 % 256)
+}
+
+pub fn part_one(day: &Day15) -> u64 {
+    day.steps.iter().map(|x| hash(x)).sum()
+}
+
+/// Keeps every lens in one list, in the order they went into their boxes,
+/// and only sorts them into boxes at the end.
+pub fn part_two(day: &Day15) -> u64 {
+    let mut lenses: Vec<(&str, u64)> = vec![];
+    for step in day.steps.iter() {
+        match step.split_once('=') {
+            Some((label, focal_length)) => {
+                let focal_length = focal_length.parse().unwrap();
+                match lenses.iter_mut().find(|(x, _)| *x == label) {
+                    Some(lens) => lens.1 = focal_length,
+                    None => lenses.push((label, focal_length)),
+                }
+            }
+            None => {
+                let label = step.trim_end_matches('-');
+                lenses.retain(|(x, _)| *x != label);
+            }
+        }
+    }
+
+    (0..256)
+        .map(|number| {
+            lenses
+                .iter()
+                .filter(|(label, _)| hash(label) == number)
+                .zip(1..)
+                .map(|((_, focal_length), slot)| (number + 1) * slot * focal_length)
+                .sum::<u64>()
+        })
+        .sum()
+}
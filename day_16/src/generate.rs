@@ -0,0 +1,24 @@
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day16};
+
+/// A grid of empty space, mirrors and splitters, weighted towards empty
+/// space like the puzzle input.
+pub fn input(max_size: usize) -> impl Strategy<Value = String> {
+    let cell = prop::sample::select(&['.', '.', '.', '/', '\\', '|', '-'][..]);
+
+    (1..=max_size, 1..=max_size).prop_flat_map(move |(width, height)| {
+        prop::collection::vec(
+            prop::collection::vec(cell.clone(), width).prop_map(String::from_iter),
+            height,
+        )
+        .prop_map(|x| x.join("\n"))
+    })
+}
+
+common::reference_tests! {
+    Day16;
+    part_one: input(8);
+    part_two: input(8);
+}
@@ -0,0 +1,185 @@
+use std::collections::HashSet;
+
+use common::{Error, ParseError, Solution};
+use grid::{Direction, Grid, Vector2d};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+static TEST_INPUT: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+struct Mirror {
+    elem: u8,
+}
+
+enum MirrorEncounter {
+    Stop,
+    GoInDirection(Direction),
+    SplitInDirections([Direction; 2]),
+}
+
+impl From<Direction> for MirrorEncounter {
+    fn from(value: Direction) -> Self {
+        MirrorEncounter::GoInDirection(value)
+    }
+}
+
+impl From<(Direction, Direction)> for MirrorEncounter {
+    fn from(value: (Direction, Direction)) -> Self {
+        MirrorEncounter::SplitInDirections([value.0, value.1])
+    }
+}
+
+impl Mirror {
+    fn behaviour(&self, direction: Direction) -> MirrorEncounter {
+        match self.elem {
+            b'/' if direction.is_horizontal() => direction.turn_left().into(),
+            b'/' => direction.turn_right().into(),
+            b'\\' if direction.is_horizontal() => direction.turn_right().into(),
+            b'\\' => direction.turn_left().into(),
+            b'|' if direction.is_horizontal() => {
+                (direction.turn_left(), direction.turn_right()).into()
+            }
+            b'-' if !direction.is_horizontal() => {
+                (direction.turn_left(), direction.turn_right()).into()
+            }
+            b'|' | b'-' | b'.' => direction.into(),
+            _ => MirrorEncounter::Stop,
+        }
+    }
+}
+
+type MirrorMap = Grid<Mirror>;
+
+fn parse_map(input: &str) -> Result<MirrorMap, ParseError> {
+    Grid::parse(input, |c| match c {
+        '.' | '/' | '\\' | '|' | '-' => Ok(Mirror { elem: c as u8 }),
+        _ => Err("bad data in map"),
+    })
+}
+
+#[derive(Hash, PartialEq, Eq, Clone)]
+struct Encountered {
+    position: Vector2d,
+    direction: Direction,
+}
+
+fn add_encounters_to_set(
+    set: &mut HashSet<Encountered>,
+    map: &MirrorMap,
+    position: Vector2d,
+    direction: Direction,
+) {
+    let behaviour = map
+        .get_or(position.0, position.1, Mirror { elem: b'E' })
+        .behaviour(direction);
+
+    if !matches!(behaviour, MirrorEncounter::Stop)
+        && !set.insert(Encountered {
+            position,
+            direction,
+        })
+    {
+        return;
+    }
+
+    match behaviour {
+        MirrorEncounter::Stop => {}
+        MirrorEncounter::GoInDirection(next_direction) => {
+            add_encounters_to_set(
+                set,
+                map,
+                position + next_direction.to_vector(),
+                next_direction,
+            );
+        }
+        MirrorEncounter::SplitInDirections([a, b]) => {
+            add_encounters_to_set(set, map, position + a.to_vector(), a);
+            add_encounters_to_set(set, map, position + b.to_vector(), b);
+        }
+    }
+}
+
+fn first_task(map: &MirrorMap) -> u64 {
+    let mut set = HashSet::new();
+
+    add_encounters_to_set(&mut set, map, (0, 0).into(), Direction::East);
+
+    let locations = set.iter().map(|x| x.position).collect::<HashSet<_>>();
+
+    locations.len().try_into().unwrap()
+}
+
+#[test]
+fn check_first_task() {
+    assert_eq!(Day16::solve_part_one(TEST_INPUT), Ok(46));
+}
+
+fn second_task(map: &MirrorMap) -> u64 {
+    (0..map.width())
+        .flat_map(|x| {
+            [
+                ((x, 0), Direction::South),
+                ((x, map.height() - 1), Direction::North),
+            ]
+        })
+        .chain((0..map.height()).flat_map(|y| {
+            [
+                ((0, y), Direction::East),
+                ((map.width() - 1, y), Direction::West),
+            ]
+        }))
+        .map(|(position, direction)| {
+            let mut set = HashSet::new();
+
+            add_encounters_to_set(&mut set, map, position.into(), direction);
+
+            let locations = set.iter().map(|x| x.position).collect::<HashSet<_>>();
+            locations.len().try_into().unwrap()
+        })
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn check_second_task() {
+    assert_eq!(Day16::solve_part_two(TEST_INPUT), Ok(51));
+}
+
+pub struct Day16 {
+    map: MirrorMap,
+}
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day16 {
+            map: parse_map(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u64, Error> {
+        Ok(first_task(&self.map))
+    }
+
+    fn part_two(&self) -> Result<u64, Error> {
+        Ok(second_task(&self.map))
+    }
+}
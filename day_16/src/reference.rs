@@ -0,0 +1,60 @@
+use std::collections::HashSet;
+
+use crate::Day16;
+
+/// Follows every beam one cell at a time, without the help of
+/// `Mirror::behaviour`, counting the cells any beam passes through.
+fn energised(day: &Day16, start: (i32, i32), direction: (i32, i32)) -> u64 {
+    let map = &day.map;
+    let mut seen = HashSet::new();
+    let mut beams = vec![(start, direction)];
+
+    while let Some(((x, y), (dx, dy))) = beams.pop() {
+        let Some(mirror) = map.get(x, y) else {
+            continue;
+        };
+        if !seen.insert(((x, y), (dx, dy))) {
+            continue;
+        }
+
+        let next = match (mirror.elem, dx) {
+            (b'/', _) => vec![(-dy, -dx)],
+            (b'\\', _) => vec![(dy, dx)],
+            (b'|', 0) | (b'-', 1 | -1) | (b'.', _) => vec![(dx, dy)],
+            (b'|', _) => vec![(0, -1), (0, 1)],
+            (b'-', _) => vec![(-1, 0), (1, 0)],
+            _ => unreachable!(),
+        };
+        for (dx, dy) in next {
+            beams.push(((x + dx, y + dy), (dx, dy)));
+        }
+    }
+
+    seen.iter()
+        .map(|&(position, _)| position)
+        .collect::<HashSet<_>>()
+        .len() as u64
+}
+
+pub fn part_one(day: &Day16) -> u64 {
+    energised(day, (0, 0), (1, 0))
+}
+
+pub fn part_two(day: &Day16) -> u64 {
+    let (width, height) = (day.map.width(), day.map.height());
+    let mut starts = vec![];
+    for x in 0..width {
+        starts.push(((x, 0), (0, 1)));
+        starts.push(((x, height - 1), (0, -1)));
+    }
+    for y in 0..height {
+        starts.push(((0, y), (1, 0)));
+        starts.push(((width - 1, y), (-1, 0)));
+    }
+
+    starts
+        .into_iter()
+        .map(|(start, direction)| energised(day, start, direction))
+        .max()
+        .unwrap()
+}
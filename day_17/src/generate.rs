@@ -0,0 +1,22 @@
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day17};
+
+/// A grid of heat loss digits. The crucible has to be able to take a step
+/// each way from the start, so it is at least 2x2.
+pub fn input(max_size: usize) -> impl Strategy<Value = String> {
+    (2..=max_size, 2..=max_size).prop_flat_map(|(width, height)| {
+        prop::collection::vec(
+            prop::collection::vec(prop::char::range('1', '9'), width).prop_map(String::from_iter),
+            height,
+        )
+        .prop_map(|x| x.join("\n"))
+    })
+}
+
+common::reference_tests! {
+    Day17;
+    part_one: input(8), cases = 64;
+    part_two: input(12), cases = 64;
+}
@@ -0,0 +1,221 @@
+use common::{Error, ParseError, Solution};
+use grid::{Direction, Grid, Vector2d};
+use petgraph::{
+    stable_graph::{DefaultIx, NodeIndex},
+    Graph,
+};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+static TEST_INPUT: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+#[cfg(test)]
+static TEST_2_INPUT: &str = "111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+
+struct Node;
+
+struct Edge {
+    cost: u8,
+}
+
+type HeatLossGrid = Grid<u8>;
+
+fn parse_grid(input: &str) -> Result<HeatLossGrid, ParseError> {
+    Grid::parse(input, |c| {
+        c.to_digit(10).map(|x| x as u8).ok_or("expected a digit")
+    })
+}
+
+struct HeatLossMap {
+    graph: Graph<Node, Edge>,
+    start: NodeIndex<DefaultIx>,
+    end: NodeIndex<DefaultIx>,
+}
+
+impl HeatLossMap {
+    /// Builds a graph with a node for every cell, direction of travel and
+    /// run length, where the crucible has to move at least `min_run` blocks
+    /// before it can turn or stop, and at most `max_run` in a straight line.
+    fn construct(grid: &HeatLossGrid, min_run: usize, max_run: usize) -> Self {
+        let mut graph = Graph::new();
+
+        let (width, height) = (grid.width(), grid.height());
+
+        let nodes = grid.map(|&digit| {
+            let nodes: [Vec<_>; 4] =
+                std::array::from_fn(|_| (0..max_run).map(|_| graph.add_node(Node)).collect());
+
+            (digit, nodes)
+        });
+
+        let get = |position: Vector2d| nodes.get(position.0, position.1);
+
+        for y in 0..height {
+            for x in 0..width - 1 {
+                let (a, b) = (
+                    get(Vector2d(x, y)).unwrap(),
+                    get(Vector2d(x + 1, y)).unwrap(),
+                );
+
+                for idx in 0..max_run - 1 {
+                    graph.add_edge(
+                        a.1[Direction::East as usize][idx],
+                        b.1[Direction::East as usize][idx + 1],
+                        Edge { cost: b.0 },
+                    );
+                    graph.add_edge(
+                        b.1[Direction::West as usize][idx],
+                        a.1[Direction::West as usize][idx + 1],
+                        Edge { cost: a.0 },
+                    );
+                }
+
+                for idx in min_run - 1..max_run {
+                    for da in [Direction::North, Direction::South].into_iter() {
+                        graph.add_edge(
+                            a.1[da as usize][idx],
+                            b.1[Direction::East as usize][0],
+                            Edge { cost: b.0 },
+                        );
+                        graph.add_edge(
+                            b.1[da as usize][idx],
+                            a.1[Direction::West as usize][0],
+                            Edge { cost: a.0 },
+                        );
+                    }
+                }
+            }
+        }
+
+        for y in 0..height - 1 {
+            for x in 0..width {
+                let (a, b) = (
+                    get(Vector2d(x, y)).unwrap(),
+                    get(Vector2d(x, y + 1)).unwrap(),
+                );
+
+                for idx in 0..max_run - 1 {
+                    graph.add_edge(
+                        a.1[Direction::South as usize][idx],
+                        b.1[Direction::South as usize][idx + 1],
+                        Edge { cost: b.0 },
+                    );
+                    graph.add_edge(
+                        b.1[Direction::North as usize][idx],
+                        a.1[Direction::North as usize][idx + 1],
+                        Edge { cost: a.0 },
+                    );
+                }
+
+                for idx in min_run - 1..max_run {
+                    for da in [Direction::East, Direction::West].into_iter() {
+                        graph.add_edge(
+                            a.1[da as usize][idx],
+                            b.1[Direction::South as usize][0],
+                            Edge { cost: b.0 },
+                        );
+                        graph.add_edge(
+                            b.1[da as usize][idx],
+                            a.1[Direction::North as usize][0],
+                            Edge { cost: a.0 },
+                        );
+                    }
+                }
+            }
+        }
+
+        let start = graph.add_node(Node);
+        let end = graph.add_node(Node);
+
+        // A grid only one block wide or tall has no start in that direction.
+        if let Some(a) = get((1, 0).into()) {
+            graph.add_edge(start, a.1[Direction::East as usize][0], Edge { cost: a.0 });
+        }
+        if let Some(a) = get((0, 1).into()) {
+            graph.add_edge(start, a.1[Direction::South as usize][0], Edge { cost: a.0 });
+        }
+
+        let end_nodes = &get((width - 1, height - 1).into()).unwrap().1;
+
+        for node in end_nodes.iter().flat_map(|x| x.iter().skip(min_run - 1)) {
+            graph.add_edge(*node, end, Edge { cost: 0 });
+        }
+
+        Self { graph, start, end }
+    }
+
+    fn solve(&self) -> Result<u64, Error> {
+        let nodes = petgraph::algo::dijkstra(&self.graph, self.start, Some(self.end), |e| {
+            e.weight().cost as u64
+        });
+
+        nodes
+            .get(&self.end)
+            .copied()
+            .ok_or(Error::NoSolution("the crucible can't reach the factory"))
+    }
+}
+
+#[test]
+fn check_first_task() {
+    assert_eq!(Day17::solve_part_one(TEST_INPUT), Ok(102));
+}
+
+#[test]
+fn check_second_task() {
+    assert_eq!(Day17::solve_part_two(TEST_INPUT), Ok(94));
+    assert_eq!(Day17::solve_part_two(TEST_2_INPUT), Ok(71));
+}
+
+#[test]
+fn check_narrow_grids() {
+    assert_eq!(Day17::solve_part_one("12"), Ok(2));
+    assert_eq!(Day17::solve_part_one("1\n2\n3"), Ok(5));
+    assert!(Day17::solve_part_two("12").is_err());
+    assert!(Day17::solve_part_one("1").is_err());
+}
+
+pub struct Day17 {
+    grid: HeatLossGrid,
+}
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day17 {
+            grid: parse_grid(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u64, Error> {
+        HeatLossMap::construct(&self.grid, 1, 3).solve()
+    }
+
+    fn part_two(&self) -> Result<u64, Error> {
+        HeatLossMap::construct(&self.grid, 4, 10).solve()
+    }
+}
@@ -0,0 +1,55 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+};
+
+use grid::Direction;
+
+use crate::Day17;
+
+/// Dijkstra over `(position, direction, run)` states, where `run` is how many
+/// blocks the crucible has moved in `direction` so far.
+fn least_heat_loss(day: &Day17, min_run: u32, max_run: u32) -> Option<u64> {
+    let grid = &day.grid;
+    let end = (grid.width() - 1, grid.height() - 1);
+
+    let mut seen = HashSet::new();
+    let mut queue = BinaryHeap::new();
+    for direction in [Direction::East, Direction::South] {
+        queue.push(Reverse((0, (0, 0), direction as usize, 0)));
+    }
+
+    while let Some(Reverse((loss, position, direction, run))) = queue.pop() {
+        let direction = Direction::ALL[direction];
+        if position == end && run >= min_run {
+            return Some(loss);
+        }
+        if !seen.insert((position, direction, run)) {
+            continue;
+        }
+
+        for next in [direction, direction.turn_left(), direction.turn_right()] {
+            let turning = next != direction;
+            if (turning && run < min_run) || (!turning && run == max_run) {
+                continue;
+            }
+
+            let step = next.to_vector();
+            let (x, y) = (position.0 + step.0, position.1 + step.1);
+            if let Some(&cost) = grid.get(x, y) {
+                let run = if turning { 1 } else { run + 1 };
+                queue.push(Reverse((loss + cost as u64, (x, y), next as usize, run)));
+            }
+        }
+    }
+
+    None
+}
+
+pub fn part_one(day: &Day17) -> Option<u64> {
+    least_heat_loss(day, 0, 3)
+}
+
+pub fn part_two(day: &Day17) -> Option<u64> {
+    least_heat_loss(day, 4, 10)
+}
@@ -0,0 +1,160 @@
+use std::fmt::Display;
+
+use crate::{Bag, Game};
+
+/// A round which showed more cubes of one colour than the bag holds.
+/// Rounds are numbered from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    pub round: usize,
+    pub colour: String,
+    pub shown: u32,
+    pub available: u32,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "round {} showed {} {} but the bag only has {}",
+            self.round, self.shown, self.colour, self.available
+        )
+    }
+}
+
+/// Whether one game could have been played with the bag, and if not why.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GameReport {
+    pub id: u32,
+    pub violations: Vec<Violation>,
+}
+
+impl GameReport {
+    pub fn is_possible(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Which of a set of games are possible with a bag, along with the smallest
+/// bag which would make all of them possible.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    pub games: Vec<GameReport>,
+    pub minimal_bag: Bag,
+}
+
+impl Report {
+    pub fn new(games: &[Game], bag: &Bag) -> Self {
+        Self {
+            games: games
+                .iter()
+                .map(|game| GameReport {
+                    id: game.id,
+                    violations: bag.violations(game),
+                })
+                .collect(),
+            minimal_bag: Bag::fewest_cubes(games),
+        }
+    }
+
+    pub fn impossible(&self) -> impl Iterator<Item = &GameReport> {
+        self.games.iter().filter(|x| !x.is_possible())
+    }
+
+    pub fn possible_id_sum(&self) -> u32 {
+        self.games
+            .iter()
+            .filter(|x| x.is_possible())
+            .map(|x| x.id)
+            .sum()
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for game in self.games.iter() {
+            write!(f, "Game {}: ", game.id)?;
+            if game.is_possible() {
+                writeln!(f, "possible")?;
+            }
+            for (idx, violation) in game.violations.iter().enumerate() {
+                let separator = if idx + 1 == game.violations.len() {
+                    "\n"
+                } else {
+                    ", "
+                };
+                write!(f, "{}{}", violation, separator)?;
+            }
+        }
+
+        write!(f, "the smallest bag for every game is {}", self.minimal_bag)
+    }
+}
+
+impl Bag {
+    /// Every colour in every round of `game` which this bag doesn't have
+    /// enough of.
+    pub fn violations(&self, game: &Game) -> Vec<Violation> {
+        game.rounds
+            .iter()
+            .zip(1..)
+            .flat_map(|(round, number)| {
+                round
+                    .cubes
+                    .iter()
+                    .filter(|x| x.count > self.count(&x.colour))
+                    .map(move |x| Violation {
+                        round: number,
+                        colour: x.colour.clone(),
+                        shown: x.count,
+                        available: self.count(&x.colour),
+                    })
+            })
+            .collect()
+    }
+
+    /// The fewest cubes of each colour which make every one of `games`
+    /// possible.
+    pub fn fewest_cubes(games: &[Game]) -> Self {
+        games
+            .iter()
+            .map(Game::fewest_cubes)
+            .fold(Bag::default(), Bag::max_of)
+    }
+}
+
+#[test]
+fn check_report() {
+    use common::Solution;
+
+    let day = crate::Day2::parse(crate::TEST_INPUT).unwrap();
+    let report = day.report(&Bag::puzzle());
+
+    assert_eq!(report.possible_id_sum(), 8);
+    assert_eq!(
+        report.impossible().map(|x| x.id).collect::<Vec<_>>(),
+        vec![3, 4]
+    );
+    assert_eq!(
+        report.games[3].violations,
+        vec![
+            Violation {
+                round: 3,
+                colour: "blue".to_string(),
+                shown: 15,
+                available: 14,
+            },
+            Violation {
+                round: 3,
+                colour: "red".to_string(),
+                shown: 14,
+                available: 12,
+            }
+        ]
+    );
+    assert_eq!(report.minimal_bag.to_string(), "15 blue, 13 green, 20 red");
+    assert_eq!(
+        report.to_string().lines().nth(2),
+        Some("Game 3: round 1 showed 20 red but the bag only has 12")
+    );
+}
@@ -0,0 +1,40 @@
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day2};
+
+/// The puzzle's colours, and one the puzzle's bag doesn't have.
+const COLOURS: &[&str] = &["red", "green", "blue", "teal"];
+
+/// Some of the colours in any order, with counts around the puzzle's limits.
+fn round() -> impl Strategy<Value = String> {
+    let colours = prop::sample::subsequence(COLOURS, 1..=3).prop_shuffle();
+    let counts = prop::collection::vec(0..=16u32, 3);
+
+    (colours, counts).prop_map(|(colours, counts)| {
+        let cubes: Vec<_> = counts
+            .iter()
+            .zip(colours)
+            .map(|(count, colour)| format!("{} {}", count, colour))
+            .collect();
+        cubes.join(", ")
+    })
+}
+
+pub fn input() -> impl Strategy<Value = String> {
+    let game = prop::collection::vec(round(), 1..=3).prop_map(|x| x.join("; "));
+
+    prop::collection::vec(game, 1..=6).prop_map(|games| {
+        let lines: Vec<_> = (1..)
+            .zip(games)
+            .map(|(id, game)| format!("Game {}: {}", id, game))
+            .collect();
+        lines.join("\n")
+    })
+}
+
+common::reference_tests! {
+    Day2;
+    part_one: input();
+    part_two: input();
+}
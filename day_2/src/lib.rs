@@ -0,0 +1,368 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
+
+use common::{Error, ParseError, Solution};
+use serde::{Deserialize, Serialize};
+
+mod analysis;
+
+pub use analysis::{GameReport, Report, Violation};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+static TEST_INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+/// Some number of cubes of one colour.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cubes {
+    pub count: u32,
+    pub colour: String,
+}
+
+impl Display for Cubes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.count, self.colour)
+    }
+}
+
+/// The cubes shown in one round of a game, in the order they're listed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Round {
+    pub cubes: Vec<Cubes>,
+}
+
+impl Display for Round {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, cubes) in self.cubes.iter().enumerate() {
+            if idx != 0 {
+                write!(f, ", ")?;
+            }
+            cubes.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// One line of the game log, which displays as it would be written there.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Round>,
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
+        for (idx, round) in self.rounds.iter().enumerate() {
+            if idx != 0 {
+                write!(f, "; ")?;
+            }
+            round.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses `<count> <colour>`, where `cubes` is part of `input`.
+fn parse_cubes(input: &str, cubes: &str) -> Result<Cubes, ParseError> {
+    let cubes = cubes.trim();
+    let Some((count, colour)) = cubes.split_once(' ') else {
+        return Err(ParseError::at(input, cubes, "expected '<count> <colour>'"));
+    };
+    let count = count
+        .parse()
+        .map_err(|_| ParseError::at(input, count, "bad cube count"))?;
+    let colour = colour.trim_start();
+    if colour.is_empty() || !colour.chars().all(char::is_alphabetic) {
+        return Err(ParseError::at(input, colour, "bad colour"));
+    }
+
+    Ok(Cubes {
+        count,
+        colour: colour.to_string(),
+    })
+}
+
+/// Parses a list of cubes, each of which is part of `input`, where no colour
+/// may be repeated.
+fn parse_cube_list<'a>(
+    input: &str,
+    list: impl IntoIterator<Item = &'a str>,
+) -> Result<Vec<Cubes>, ParseError> {
+    let mut cubes: Vec<Cubes> = vec![];
+    for text in list {
+        let next = parse_cubes(input, text)?;
+        if cubes.iter().any(|x| x.colour == next.colour) {
+            let colour = text.trim_end();
+            let colour = &colour[colour.len() - next.colour.len()..];
+            return Err(ParseError::at(input, colour, "colour given twice"));
+        }
+        cubes.push(next);
+    }
+
+    Ok(cubes)
+}
+
+impl Game {
+    /// Parses one line, `Game <id>: <round>; <round>...`, where each round is
+    /// a list of `<count> <colour>` separated by commas.
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let Some((game, rounds)) = line.split_once(':') else {
+            return Err(ParseError::new(line, "expected 'Game <id>: '"));
+        };
+        let Some(id) = game.strip_prefix("Game ") else {
+            return Err(ParseError::at(line, game, "expected 'Game <id>'"));
+        };
+        let id = id
+            .trim()
+            .parse()
+            .map_err(|_| ParseError::at(line, id, "bad game id"))?;
+
+        let rounds = rounds
+            .split(';')
+            .map(|round| {
+                Ok(Round {
+                    cubes: parse_cube_list(line, round.split(','))?,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Game { id, rounds })
+    }
+
+    /// The fewest cubes of each colour which make the game possible.
+    pub fn fewest_cubes(&self) -> Bag {
+        self.rounds
+            .iter()
+            .map(|round| Bag {
+                cubes: round
+                    .cubes
+                    .iter()
+                    .map(|x| (x.colour.clone(), x.count))
+                    .collect(),
+            })
+            .fold(Bag::default(), Bag::max_of)
+    }
+}
+
+/// What's in the bag the cubes are drawn from.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct Bag {
+    cubes: BTreeMap<String, u32>,
+}
+
+impl Bag {
+    /// The bag from the puzzle: 12 red, 13 green and 14 blue cubes.
+    pub fn puzzle() -> Self {
+        Self::parse("12 red, 13 green, 14 blue").unwrap()
+    }
+
+    /// Parses counts like a round, `12 red, 13 green`, which can also be
+    /// separated by new lines.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let list = input.split(['\n', ',']).filter(|x| !x.trim().is_empty());
+
+        Ok(Self {
+            cubes: parse_cube_list(input, list)?
+                .into_iter()
+                .map(|x| (x.colour, x.count))
+                .collect(),
+        })
+    }
+
+    /// How many cubes of `colour` there are, which is none for colours the
+    /// bag doesn't mention.
+    pub fn count(&self, colour: &str) -> u32 {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+
+    /// The larger count of each colour from either bag.
+    fn max_of(mut self, other: Self) -> Self {
+        for (colour, count) in other.cubes {
+            let max = self.cubes.entry(colour).or_default();
+            *max = count.max(*max);
+        }
+
+        self
+    }
+
+    /// The product of the counts of each of `colours`.
+    /// The product of the counts of `colours`, if it fits in a `u64`.
+    fn power<'a>(&self, colours: impl IntoIterator<Item = &'a String>) -> Option<u64> {
+        colours
+            .into_iter()
+            .try_fold(1u64, |acc, x| acc.checked_mul(self.count(x).into()))
+    }
+}
+
+impl Display for Bag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, (colour, count)) in self.cubes.iter().enumerate() {
+            if idx != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} {}", count, colour)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn check_round_trip() {
+    for line in TEST_INPUT.lines() {
+        assert_eq!(Game::parse(line).unwrap().to_string(), line);
+    }
+
+    let game = Game::parse("Game 12:1 red ,2  blue;3 green").unwrap();
+    assert_eq!(game.to_string(), "Game 12: 1 red, 2 blue; 3 green");
+
+    let json = r#"{"id":12,"rounds":[[{"count":1,"colour":"red"},{"count":2,"colour":"blue"}],[{"count":3,"colour":"green"}]]}"#;
+    assert_eq!(serde_json::to_string(&game).unwrap(), json);
+    assert_eq!(serde_json::from_str::<Game>(json).unwrap(), game);
+}
+
+#[test]
+fn check_parse_errors() {
+    let error = |line| {
+        let error = Game::parse(line).err().unwrap();
+        (error.column, error.message)
+    };
+
+    assert_eq!(error("Game 1 3 red"), (1, "expected 'Game <id>: '".into()));
+    assert_eq!(error("Gme 1: 3 red"), (1, "expected 'Game <id>'".into()));
+    assert_eq!(error("Game x: 3 red"), (6, "bad game id".into()));
+    assert_eq!(
+        error("Game 1: 3 red; x blue"),
+        (16, "bad cube count".into())
+    );
+    assert_eq!(
+        error("Game 1: 3 red, 4"),
+        (16, "expected '<count> <colour>'".into())
+    );
+    assert_eq!(error("Game 1: 3 red, 4 red!"), (18, "bad colour".into()));
+    assert_eq!(
+        error("Game 1: 3 red, 4 red"),
+        (18, "colour given twice".into())
+    );
+}
+
+fn first_task(games: &[Game], bag: &Bag) -> u32 {
+    games
+        .iter()
+        .filter(|game| bag.violations(game).is_empty())
+        .map(|game| game.id)
+        .sum()
+}
+
+#[test]
+fn check_first_task() {
+    assert_eq!(Day2::solve_part_one(TEST_INPUT), Ok(8));
+}
+
+#[test]
+fn check_other_bags() {
+    let day = Day2::parse(TEST_INPUT).unwrap();
+
+    let bag = Bag::parse("19 red\n13 green\n15 blue").unwrap();
+    assert_eq!(day.possible_id_sum(&bag), 12);
+    let bag = Bag::parse("100 red, 100 blue").unwrap();
+    assert_eq!(day.possible_id_sum(&bag), 0);
+
+    let error = Bag::parse("1 red, 2 red").err().unwrap();
+    assert_eq!((error.line, error.column), (1, 10));
+}
+
+/// The power of the fewest cubes which make each game possible, over every
+/// colour seen in any game.
+fn second_task(games: &[Game]) -> Result<u64, Error> {
+    let fewest: Vec<_> = games.iter().map(Game::fewest_cubes).collect();
+    let colours: BTreeSet<_> = fewest.iter().flat_map(|x| x.cubes.keys()).collect();
+
+    fewest
+        .iter()
+        .try_fold(0u64, |acc, x| {
+            acc.checked_add(x.power(colours.iter().copied())?)
+        })
+        .ok_or(Error::NoSolution("the power doesn't fit in a u64"))
+}
+
+#[test]
+fn check_second_task() {
+    assert_eq!(Day2::solve_part_two(TEST_INPUT), Ok(2286));
+    assert_eq!(
+        Day2::solve_part_two("Game 1: 2 teal, 3 red\nGame 2: 1 teal, 4 red, 2 mauve"),
+        Ok(8)
+    );
+
+    // 99^6 doesn't fit in a u32, and 99^10 doesn't fit in a u64
+    let colours = ["red", "green", "blue", "cyan", "pink", "gold"];
+    let game = |colours: &[&str]| {
+        let cubes: Vec<_> = colours.iter().map(|x| format!("99 {}", x)).collect();
+        format!("Game 1: {}", cubes.join(", "))
+    };
+    assert_eq!(Day2::solve_part_two(&game(&colours)), Ok(99u64.pow(6)));
+    let colours = [&colours[..], &["teal", "plum", "rose", "sand"]].concat();
+    assert_eq!(
+        Day2::solve_part_two(&game(&colours)),
+        Err(Error::NoSolution("the power doesn't fit in a u64"))
+    );
+}
+
+pub struct Day2 {
+    games: Vec<Game>,
+}
+
+impl Day2 {
+    pub fn games(&self) -> &[Game] {
+        &self.games
+    }
+
+    /// The sum of the ids of the games which are possible with `bag`.
+    pub fn possible_id_sum(&self, bag: &Bag) -> u32 {
+        first_task(&self.games, bag)
+    }
+
+    /// Explains which games are possible with `bag`, and why the others
+    /// aren't.
+    pub fn report(&self, bag: &Bag) -> Report {
+        Report::new(&self.games, bag)
+    }
+}
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day2 {
+            games: input
+                .lines()
+                .map(|line| Game::parse(line).map_err(|e| e.within(input, line)))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u32, Error> {
+        Ok(self.possible_id_sum(&Bag::puzzle()))
+    }
+
+    fn part_two(&self) -> Result<u64, Error> {
+        second_task(&self.games)
+    }
+}
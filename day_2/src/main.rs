@@ -1,109 +1,69 @@
-use std::sync::OnceLock;
+use clap::Parser;
+use common::{exit, Solution};
+use day_2::{Bag, Day2, Game};
 
-use regex::Regex;
-
-const INPUT: &str = include_str!("input.txt");
-
-#[derive(Default)]
-struct Draw {
-    red: u32,
-    green: u32,
-    blue: u32,
+/// Solves day 2, optionally with another bag for part 1, or converts the
+/// game log to and from JSON.
+#[derive(Parser)]
+struct Args {
+    /// Input file, or `-` for stdin. Defaults to `inputs/day_2.txt`
+    input: Option<String>,
+    /// Bag for part 1, like "12 red, 13 green"
+    #[arg(long, value_parser = parse_bag, conflicts_with = "bag_file")]
+    bag: Option<Bag>,
+    /// File with the bag for part 1, as counts separated by commas or lines
+    #[arg(long, value_parser = read_bag)]
+    bag_file: Option<Bag>,
+    /// Print which games are possible, and why the others aren't
+    #[arg(long)]
+    explain: bool,
+    /// Print the games as JSON instead of solving
+    #[arg(long, conflicts_with = "from_json")]
+    to_json: bool,
+    /// Read the input as JSON games and print them as a game log
+    #[arg(long)]
+    from_json: bool,
 }
 
-impl Draw {
-    fn max_of(a: Self, b: Self) -> Self {
-        Draw {
-            red: a.red.max(b.red),
-            green: a.green.max(b.green),
-            blue: a.blue.max(b.blue),
-        }
-    }
+fn parse_bag(text: &str) -> Result<Bag, String> {
+    Bag::parse(text).map_err(|e| format!("bad bag: {}", e))
 }
 
-fn game_regex() -> &'static Regex {
-    static GAME_REGEX: OnceLock<Regex> = OnceLock::new();
-    GAME_REGEX.get_or_init(|| Regex::new(r"Game (\d*)").unwrap())
+fn read_bag(path: &str) -> Result<Bag, String> {
+    let text =
+        std::fs::read_to_string(path).map_err(|e| format!("couldn't read bag {}: {}", path, e))?;
+    parse_bag(&text)
 }
 
-fn draw_regex() -> &'static Regex {
-    static GAME_REGEX: OnceLock<Regex> = OnceLock::new();
-    GAME_REGEX.get_or_init(|| Regex::new(r"(\d*) (red|blue|green)").unwrap())
-}
-
-fn input_parse(input: &str) -> (u32, impl Iterator<Item = Draw> + '_) {
-    let mut s = input.split(':').into_iter();
-    let game_count = s.next().unwrap();
-    let draws = s.next().unwrap();
-    let game_count: u32 = game_regex()
-        .captures(game_count)
-        .unwrap()
-        .get(1)
-        .unwrap()
-        .as_str()
-        .parse()
-        .unwrap();
-
-    let draw_re = draw_regex();
-    (
-        game_count,
-        draws.split(';').into_iter().map(|draw| {
-            let mut cubes = Draw::default();
-            for cube in draw.split(',') {
-                let draw_capture = draw_re.captures(cube).unwrap();
-                let count: u32 = draw_capture.get(1).unwrap().as_str().parse().unwrap();
-                let colour_name = draw_capture.get(2).unwrap().as_str();
-                let r = match colour_name {
-                    "red" => &mut cubes.red,
-                    "blue" => &mut cubes.blue,
-                    "green" => &mut cubes.green,
-                    _ => panic!("unknown colour {}", colour_name),
-                };
-                *r = count;
-            }
-            cubes
-        }),
-    )
-}
+fn main() {
+    let args = Args::parse();
+    let bag = args.bag.or(args.bag_file).unwrap_or_else(Bag::puzzle);
 
-fn first_task() {
-    let answer: u32 = INPUT
-        .lines()
-        .map(|line| input_parse(line))
-        .map(|(gc, draws)| {
-            (
-                gc,
-                draws
-                    .reduce(|acc, e| Draw::max_of(acc, e))
-                    .unwrap_or(Draw::default()),
-            )
-        })
-        .filter(|(_, game)| game.red <= 12 && game.green <= 13 && game.blue <= 14)
-        .map(|(gc, _)| gc)
-        .sum();
+    let input = common::input::read(Day2::DAY, args.input.as_deref()).unwrap_or_else(|e| exit(e));
 
-    dbg!(answer);
-}
+    if args.from_json {
+        let games: Vec<Game> = serde_json::from_str(&input)
+            .unwrap_or_else(|e| exit(format!("couldn't read games: {}", e)));
+        for game in games {
+            println!("{}", game);
+        }
+        return;
+    }
 
-fn second_task() {
-    let answer: u32 = INPUT
-        .lines()
-        .map(|line| input_parse(line))
-        .map(|(gc, draws)| {
-            (
-                gc,
-                draws
-                    .reduce(|acc, e| Draw::max_of(acc, e))
-                    .unwrap_or(Draw::default()),
-            )
-        })
-        .map(|(_, game)| game.red * game.green * game.blue)
-        .sum();
+    let day =
+        Day2::parse(&common::normalise(&input)).unwrap_or_else(|e| exit(e.for_day(Day2::DAY)));
 
-    dbg!(answer);
-}
+    if args.to_json {
+        println!("{}", serde_json::to_string_pretty(day.games()).unwrap());
+        return;
+    }
 
-fn main() {
-    first_task();
-    second_task();
+    if args.explain {
+        println!("{}", day.report(&bag));
+    }
+    println!("part 1: {}", day.possible_id_sum(&bag));
+    match day.part_two() {
+        Ok(answer) => println!("part 2: {}", answer),
+        Err(e) => eprintln!("part 2: {}", e),
+    }
 }
@@ -0,0 +1,54 @@
+use std::collections::BTreeSet;
+
+use crate::{Day2, Game};
+
+/// The most cubes of `colour` shown at once in `game`.
+fn most(game: &Game, colour: &str) -> u32 {
+    game.rounds
+        .iter()
+        .flat_map(|x| x.cubes.iter())
+        .filter(|x| x.colour == colour)
+        .map(|x| x.count)
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn part_one(day: &Day2) -> u32 {
+    let limit = |colour: &str| match colour {
+        "red" => 12,
+        "green" => 13,
+        "blue" => 14,
+        _ => 0,
+    };
+
+    day.games
+        .iter()
+        .filter(|game| {
+            game.rounds
+                .iter()
+                .flat_map(|x| x.cubes.iter())
+                .all(|x| x.count <= limit(&x.colour))
+        })
+        .map(|game| game.id)
+        .sum()
+}
+
+pub fn part_two(day: &Day2) -> u64 {
+    let colours: BTreeSet<_> = day
+        .games
+        .iter()
+        .flat_map(|x| x.rounds.iter())
+        .flat_map(|x| x.cubes.iter())
+        .map(|x| x.colour.as_str())
+        .collect();
+
+    day.games
+        .iter()
+        .map(|game| {
+            colours
+                .iter()
+                .map(|x| u64::from(most(game, x)))
+                .product::<u64>()
+        })
+        .sum()
+}
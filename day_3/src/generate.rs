@@ -0,0 +1,28 @@
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day3};
+
+/// A schematic which is mostly `.`, with runs of digits and a few symbols,
+/// more of them gears than anything else.
+pub fn input() -> impl Strategy<Value = String> {
+    let cell = prop::sample::select(&b"......123456789****#$+"[..]);
+
+    (1..=8usize, 1..=8usize)
+        .prop_flat_map(move |(width, height)| {
+            prop::collection::vec(prop::collection::vec(cell.clone(), width), height)
+        })
+        .prop_map(|rows| {
+            let rows: Vec<_> = rows
+                .into_iter()
+                .map(|x| String::from_utf8(x).unwrap())
+                .collect();
+            rows.join("\n")
+        })
+}
+
+common::reference_tests! {
+    Day3;
+    part_one: input();
+    part_two: input();
+}
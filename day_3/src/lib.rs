@@ -0,0 +1,144 @@
+use common::{Error, ParseError, Solution};
+use grid::Grid;
+
+mod rules;
+mod schematic;
+
+pub use rules::{Aggregate, GearRule, SymbolClass};
+pub use schematic::{Neighbourhood, Number, Options, Schematic, Symbol};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+static TEST_INPUT: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+#[test]
+fn check_get() {
+    let input = Grid::parse_ascii(TEST_INPUT).unwrap();
+    assert_eq!(input.get_or(0, 0, b'.'), b'4');
+    assert_eq!(input.get_or(0, 4, b'.'), b'6');
+    assert_eq!(input.get_or(9, 3, b'.'), b'.');
+    assert_eq!(input.get_or(10, 3, b'.'), b'.');
+    assert_eq!(input.get_or(11, 3, b'.'), b'.');
+}
+
+fn first_task(schematic: &Schematic, class: &SymbolClass) -> Result<i64, Error> {
+    schematic
+        .part_numbers(class)
+        .try_fold(0i64, |acc, x| acc.checked_add(x.value))
+        .ok_or(Error::NoSolution("the sum doesn't fit in an i64"))
+}
+
+#[test]
+fn check_first_task() {
+    assert_eq!(Day3::solve_part_one(TEST_INPUT), Ok(4361));
+}
+
+fn second_task(schematic: &Schematic, rule: &GearRule) -> Result<i64, Error> {
+    schematic
+        .gear_values(rule)
+        .try_fold(0i64, |acc, x| acc.checked_add(x?))
+        .ok_or(Error::NoSolution("the sum doesn't fit in an i64"))
+}
+
+#[test]
+fn check_second_task() {
+    assert_eq!(Day3::solve_part_two(TEST_INPUT), Ok(467835));
+}
+
+#[test]
+fn check_overflow() {
+    let too_large = Err(Error::NoSolution("the sum doesn't fit in an i64"));
+
+    let input = "9999999999.\n*..........\n9999999999.";
+    assert_eq!(Day3::solve_part_one(input), Ok(2 * 9999999999));
+    assert_eq!(Day3::solve_part_two(input), too_large);
+    assert_eq!(Day3::solve_part_one("9223372036854775807*1"), too_large);
+    let error = Day3::parse("9223372036854775808*1").err().unwrap();
+    assert_eq!(error.text, "9223372036854775808");
+    assert_eq!(error.message, "number doesn't fit in an i64");
+}
+
+#[test]
+fn check_rules() {
+    let day = Day3::parse(TEST_INPUT).unwrap();
+
+    assert_eq!(
+        day.part_number_sum(&SymbolClass::OneOf(vec![b'#', b'$'])),
+        Ok(633 + 664)
+    );
+    assert_eq!(
+        day.part_number_sum(&SymbolClass::NoneOf(vec![b'*'])),
+        Ok(633 + 592 + 664)
+    );
+
+    let rule = GearRule {
+        symbols: SymbolClass::Any,
+        neighbours: 1,
+        aggregate: Aggregate::Max,
+    };
+    assert_eq!(day.gear_sum(&rule), Ok(633 + 617 + 592 + 664));
+    let rule = GearRule {
+        aggregate: Aggregate::Sum,
+        ..GearRule::puzzle()
+    };
+    assert_eq!(day.gear_sum(&rule), Ok(467 + 35 + 755 + 598));
+}
+
+pub struct Day3 {
+    schematic: Schematic,
+}
+
+impl Day3 {
+    /// Like [`Solution::parse`], but reading the schematic with `options`.
+    pub fn parse_with(input: &str, options: &Options) -> Result<Self, ParseError> {
+        Ok(Day3 {
+            schematic: Schematic::new(&Grid::parse_ascii(input)?, options)?,
+        })
+    }
+
+    pub fn schematic(&self) -> &Schematic {
+        &self.schematic
+    }
+
+    /// The sum of the numbers next to a symbol in `class`.
+    pub fn part_number_sum(&self, class: &SymbolClass) -> Result<i64, Error> {
+        first_task(&self.schematic, class)
+    }
+
+    /// The sum of the values of the gears under `rule`.
+    pub fn gear_sum(&self, rule: &GearRule) -> Result<i64, Error> {
+        second_task(&self.schematic, rule)
+    }
+}
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, &Options::default())
+    }
+
+    fn part_one(&self) -> Result<i64, Error> {
+        self.part_number_sum(&SymbolClass::Any)
+    }
+
+    fn part_two(&self) -> Result<i64, Error> {
+        self.gear_sum(&GearRule::puzzle())
+    }
+}
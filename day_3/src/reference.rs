@@ -0,0 +1,37 @@
+use crate::{Day3, Number, Symbol};
+
+/// Whether `symbol` is in the box one cell bigger than `number` all round.
+fn touches(number: &Number, symbol: &Symbol) -> bool {
+    let (x, y) = symbol.position;
+    (number.start - 1..=number.end).contains(&x) && (number.y - 1..=number.y + 1).contains(&y)
+}
+
+pub fn part_one(day: &Day3) -> i64 {
+    let schematic = &day.schematic;
+    schematic
+        .numbers
+        .iter()
+        .filter(|number| schematic.symbols.iter().any(|x| touches(number, x)))
+        .map(|x| x.value)
+        .sum()
+}
+
+pub fn part_two(day: &Day3) -> i64 {
+    let schematic = &day.schematic;
+    schematic
+        .symbols
+        .iter()
+        .filter(|x| x.kind == b'*')
+        .filter_map(|symbol| {
+            let numbers: Vec<_> = schematic
+                .numbers
+                .iter()
+                .filter(|x| touches(x, symbol))
+                .collect();
+            match numbers.as_slice() {
+                [a, b] => Some(a.value * b.value),
+                _ => None,
+            }
+        })
+        .sum()
+}
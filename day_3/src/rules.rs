@@ -0,0 +1,60 @@
+/// Which kinds of symbol a rule looks at.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SymbolClass {
+    /// Anything which is neither a digit nor a `.`.
+    Any,
+    OneOf(Vec<u8>),
+    NoneOf(Vec<u8>),
+}
+
+impl SymbolClass {
+    pub fn contains(&self, kind: u8) -> bool {
+        match self {
+            SymbolClass::Any => true,
+            SymbolClass::OneOf(kinds) => kinds.contains(&kind),
+            SymbolClass::NoneOf(kinds) => !kinds.contains(&kind),
+        }
+    }
+}
+
+/// How the numbers next to a gear are combined into its value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Aggregate {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Aggregate {
+    /// Combines `values`, or gives `None` if the result doesn't fit in an
+    /// `i64`.
+    pub fn apply(self, values: impl IntoIterator<Item = i64>) -> Option<i64> {
+        let mut values = values.into_iter();
+        match self {
+            Aggregate::Product => values.try_fold(1i64, |acc, x| acc.checked_mul(x)),
+            Aggregate::Sum => values.try_fold(0i64, |acc, x| acc.checked_add(x)),
+            Aggregate::Max => Some(values.max().unwrap_or(0)),
+        }
+    }
+}
+
+/// What makes a symbol a gear, and what it's worth.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GearRule {
+    pub symbols: SymbolClass,
+    /// How many numbers have to be next to the symbol.
+    pub neighbours: usize,
+    pub aggregate: Aggregate,
+}
+
+impl GearRule {
+    /// The rule from the puzzle: a `*` next to exactly two numbers, worth
+    /// their product.
+    pub fn puzzle() -> Self {
+        Self {
+            symbols: SymbolClass::OneOf(vec![b'*']),
+            neighbours: 2,
+            aggregate: Aggregate::Product,
+        }
+    }
+}
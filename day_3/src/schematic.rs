@@ -0,0 +1,364 @@
+use common::ParseError;
+use grid::Grid;
+
+use crate::{GearRule, SymbolClass};
+
+/// A number written along row `y` of the schematic, covering columns
+/// `start..end`, including its sign if it has one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Number {
+    pub value: i64,
+    pub y: i32,
+    pub start: i32,
+    pub end: i32,
+}
+
+impl Number {
+    pub fn positions(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        (self.start..self.end).map(|x| (x, self.y))
+    }
+}
+
+/// Anything in the schematic which is neither a digit nor a `.`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Symbol {
+    pub kind: u8,
+    pub position: (i32, i32),
+}
+
+/// Which cells count as next to a cell.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// Only the cells above, below and to either side.
+    Four,
+    /// The surrounding cells, including diagonally.
+    #[default]
+    Eight,
+    /// Every cell at most this many steps away in each direction.
+    Within(i32),
+}
+
+impl Neighbourhood {
+    /// The offsets from a cell to its neighbours.
+    fn offsets(self) -> Vec<(i32, i32)> {
+        let radius = match self {
+            Neighbourhood::Four => return vec![(0, -1), (1, 0), (0, 1), (-1, 0)],
+            Neighbourhood::Eight => 1,
+            Neighbourhood::Within(radius) => radius,
+        };
+
+        (-radius..=radius)
+            .flat_map(|dy| (-radius..=radius).map(move |dx| (dx, dy)))
+            .filter(|&offset| offset != (0, 0))
+            .collect()
+    }
+}
+
+/// How to read a schematic. The default is the puzzle's: unsigned numbers,
+/// with diagonals counting as adjacent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Whether a `-` directly before a number is its sign rather than a
+    /// symbol.
+    pub signed: bool,
+    pub neighbourhood: Neighbourhood,
+}
+
+/// Every number and symbol in an engine schematic, along with which of them
+/// are next to each other. Numbers and symbols are
+/// referred to by their index in [`Schematic::numbers`] and
+/// [`Schematic::symbols`], which are in reading order.
+#[derive(Clone, Debug)]
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+    number_symbols: Vec<Vec<usize>>,
+    symbol_numbers: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    /// Finds the numbers and symbols in `grid`. Numbers which don't fit in
+    /// an `i64` are an error, even unsigned ones which would fit in a `u64`,
+    /// so that signed and unsigned schematics give the same kind of answer.
+    pub fn new(grid: &Grid<u8>, options: &Options) -> Result<Self, ParseError> {
+        let mut numbers = vec![];
+        let mut symbols = vec![];
+        let mut symbol_at = grid.map(|_| None);
+
+        for y in 0..grid.height() {
+            let mut number: Option<Number> = None;
+            for x in 0..grid.width() + 1 {
+                let value = grid.get_or(x, y, b'.');
+                if let Some(digit) = (value as char).to_digit(10) {
+                    let number = number.get_or_insert(Number {
+                        value: 0,
+                        y,
+                        start: x,
+                        end: x,
+                    });
+                    let digit = digit as i64;
+                    let value = if grid[(number.start, y)] == b'-' {
+                        number
+                            .value
+                            .checked_mul(10)
+                            .and_then(|x| x.checked_sub(digit))
+                    } else {
+                        number
+                            .value
+                            .checked_mul(10)
+                            .and_then(|x| x.checked_add(digit))
+                    };
+                    number.value = value.ok_or_else(|| too_large(grid, number))?;
+                    number.end = x + 1;
+                    continue;
+                }
+
+                numbers.extend(number.take());
+                if value == b'-' && options.signed && grid.get_or(x + 1, y, b'.').is_ascii_digit() {
+                    number = Some(Number {
+                        value: 0,
+                        y,
+                        start: x,
+                        end: x + 1,
+                    });
+                    continue;
+                }
+                if value != b'.' {
+                    symbol_at[(x, y)] = Some(symbols.len());
+                    symbols.push(Symbol {
+                        kind: value,
+                        position: (x, y),
+                    });
+                }
+            }
+        }
+
+        let offsets = options.neighbourhood.offsets();
+        let mut number_symbols = vec![vec![]; numbers.len()];
+        let mut symbol_numbers = vec![vec![]; symbols.len()];
+        for (number, neighbours) in numbers.iter().zip(number_symbols.iter_mut()) {
+            for (x, y) in number.positions() {
+                for (dx, dy) in offsets.iter() {
+                    if let Some(&Some(symbol)) = symbol_at.get(x + dx, y + dy) {
+                        if !neighbours.contains(&symbol) {
+                            neighbours.push(symbol);
+                        }
+                    }
+                }
+            }
+            neighbours.sort();
+        }
+        for (number, neighbours) in number_symbols.iter().enumerate() {
+            for &symbol in neighbours {
+                symbol_numbers[symbol].push(number);
+            }
+        }
+
+        Ok(Self {
+            numbers,
+            symbols,
+            number_symbols,
+            symbol_numbers,
+        })
+    }
+
+    /// The symbols next to the number with index `number`.
+    pub fn symbols_next_to(&self, number: usize) -> &[usize] {
+        &self.number_symbols[number]
+    }
+
+    /// The numbers next to the symbol with index `symbol`.
+    pub fn numbers_next_to(&self, symbol: usize) -> &[usize] {
+        &self.symbol_numbers[symbol]
+    }
+
+    /// Numbers next to at least one symbol in `class`.
+    pub fn part_numbers<'a>(&'a self, class: &'a SymbolClass) -> impl Iterator<Item = &'a Number> {
+        self.numbers
+            .iter()
+            .zip(self.number_symbols.iter())
+            .filter(|(_, symbols)| {
+                symbols
+                    .iter()
+                    .any(|&x| class.contains(self.symbols[x].kind))
+            })
+            .map(|(number, _)| number)
+    }
+
+    /// Numbers next to more than one symbol.
+    pub fn shared_parts(&self) -> impl Iterator<Item = &Number> {
+        self.numbers
+            .iter()
+            .zip(self.number_symbols.iter())
+            .filter(|(_, symbols)| symbols.len() > 1)
+            .map(|(number, _)| number)
+    }
+
+    /// Symbols in `class` with exactly `count` numbers next to them.
+    pub fn symbols_with<'a>(
+        &'a self,
+        class: &'a SymbolClass,
+        count: usize,
+    ) -> impl Iterator<Item = usize> + 'a {
+        (0..self.symbols.len()).filter(move |&x| {
+            class.contains(self.symbols[x].kind) && self.symbol_numbers[x].len() == count
+        })
+    }
+
+    /// The value of each gear under `rule`, or `None` for one whose value
+    /// doesn't fit in an `i64`.
+    pub fn gear_values<'a>(&'a self, rule: &'a GearRule) -> impl Iterator<Item = Option<i64>> + 'a {
+        self.symbols_with(&rule.symbols, rule.neighbours)
+            .map(|gear| {
+                rule.aggregate.apply(
+                    self.numbers_next_to(gear)
+                        .iter()
+                        .map(|&x| self.numbers[x].value),
+                )
+            })
+    }
+}
+
+/// The error for `number`, whose digits run on past `number.end`.
+fn too_large(grid: &Grid<u8>, number: &Number) -> ParseError {
+    let row = &grid.row(number.y)[number.start as usize..];
+    let length = row
+        .iter()
+        .skip(1)
+        .position(|x| !x.is_ascii_digit())
+        .map_or(row.len(), |x| x + 1);
+
+    ParseError {
+        line: number.y as usize + 1,
+        column: number.start as usize + 1,
+        ..ParseError::new(
+            &String::from_utf8_lossy(&row[..length]),
+            "number doesn't fit in an i64",
+        )
+    }
+}
+
+#[test]
+fn check_schematic() {
+    let grid = Grid::parse_ascii("12.$\n.*..\n3.45\n..*6").unwrap();
+    let schematic = Schematic::new(&grid, &Options::default()).unwrap();
+
+    assert_eq!(
+        schematic.numbers,
+        vec![
+            Number {
+                value: 12,
+                y: 0,
+                start: 0,
+                end: 2
+            },
+            Number {
+                value: 3,
+                y: 2,
+                start: 0,
+                end: 1
+            },
+            Number {
+                value: 45,
+                y: 2,
+                start: 2,
+                end: 4
+            },
+            Number {
+                value: 6,
+                y: 3,
+                start: 3,
+                end: 4
+            },
+        ]
+    );
+    assert_eq!(
+        schematic.symbols.iter().map(|x| x.kind).collect::<Vec<_>>(),
+        b"$**"
+    );
+    assert_eq!(schematic.numbers_next_to(1), [0, 1, 2]);
+    assert_eq!(schematic.symbols_next_to(2), [1, 2]);
+    assert_eq!(
+        schematic
+            .shared_parts()
+            .map(|x| x.value)
+            .collect::<Vec<_>>(),
+        vec![45]
+    );
+    assert_eq!(
+        schematic
+            .symbols_with(&SymbolClass::OneOf(vec![b'*']), 2)
+            .collect::<Vec<_>>(),
+        vec![2]
+    );
+    assert_eq!(
+        schematic
+            .gear_values(&GearRule::puzzle())
+            .collect::<Vec<_>>(),
+        vec![Some(45 * 6)]
+    );
+}
+
+#[test]
+fn check_options() {
+    let signed = Options {
+        signed: true,
+        ..Options::default()
+    };
+    let parse =
+        |input, options: Options| Schematic::new(&Grid::parse_ascii(input).unwrap(), &options);
+
+    let schematic = parse("-12.#\n...*.\n..-3.", signed).unwrap();
+    assert_eq!(
+        schematic
+            .numbers
+            .iter()
+            .map(|x| x.value)
+            .collect::<Vec<_>>(),
+        vec![-12, -3]
+    );
+    assert_eq!(
+        schematic.symbols.iter().map(|x| x.kind).collect::<Vec<_>>(),
+        b"#*"
+    );
+    assert_eq!(schematic.symbols_next_to(0), [1]);
+    assert_eq!(schematic.symbols_next_to(1), [1]);
+
+    let schematic = parse("-12.#\n...*.\n..-3.", Options::default()).unwrap();
+    assert_eq!(
+        schematic
+            .numbers
+            .iter()
+            .map(|x| x.value)
+            .collect::<Vec<_>>(),
+        vec![12, 3]
+    );
+    assert_eq!(schematic.symbols_next_to(0), [0, 2]);
+    assert_eq!(schematic.symbols_next_to(1), [2, 3]);
+
+    let four = Options {
+        neighbourhood: Neighbourhood::Four,
+        ..signed
+    };
+    let schematic = parse("-12.#\n...*.\n..-3.", four).unwrap();
+    assert_eq!(schematic.symbols_next_to(0), []);
+    assert_eq!(schematic.symbols_next_to(1), [1]);
+
+    let wide = Options {
+        neighbourhood: Neighbourhood::Within(2),
+        ..signed
+    };
+    let schematic = parse("-12.#\n...*.\n..-3.", wide).unwrap();
+    assert_eq!(schematic.symbols_next_to(0), [0, 1]);
+    assert_eq!(schematic.symbols_next_to(1), [0, 1]);
+
+    let schematic = parse(".-9223372036854775808", signed).unwrap();
+    assert_eq!(schematic.numbers[0].value, i64::MIN);
+
+    let error = parse(".-9223372036854775808", Options::default()).unwrap_err();
+    assert_eq!((error.line, error.column), (1, 3));
+    assert_eq!(error.text, "9223372036854775808");
+    let error = parse("....................\n-9223372036854775809", signed).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.text, "-9223372036854775809");
+}
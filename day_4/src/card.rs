@@ -0,0 +1,97 @@
+use std::collections::HashSet;
+
+use common::ParseError;
+
+/// One line of the pile of scratchcards, with the numbers in the order
+/// they're written.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scratchcard {
+    pub id: u32,
+    pub winning: Vec<u32>,
+    pub mine: Vec<u32>,
+}
+
+/// Parses whitespace separated numbers, none of which may appear twice.
+fn parse_numbers(line: &str, numbers: &str) -> Result<Vec<u32>, ParseError> {
+    let mut seen = HashSet::new();
+
+    numbers
+        .split_whitespace()
+        .map(|x| {
+            let number = x
+                .parse()
+                .map_err(|_| ParseError::at(line, x, "bad number"))?;
+            if !seen.insert(number) {
+                return Err(ParseError::at(line, x, "number given twice"));
+            }
+
+            Ok(number)
+        })
+        .collect()
+}
+
+impl Scratchcard {
+    /// Parses a `Card <id>: <winning numbers> | <my numbers>` line.
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let (header, numbers) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::new(line, "expected 'Card <id>: '"))?;
+        let id = header
+            .strip_prefix("Card")
+            .ok_or_else(|| ParseError::at(line, header, "expected 'Card <id>'"))?
+            .trim_start();
+        let id = id
+            .parse()
+            .map_err(|_| ParseError::at(line, id, "bad card id"))?;
+        let (winning, mine) = numbers
+            .split_once('|')
+            .ok_or_else(|| ParseError::at(line, numbers, "no '|' between the numbers"))?;
+
+        Ok(Self {
+            id,
+            winning: parse_numbers(line, winning)?,
+            mine: parse_numbers(line, mine)?,
+        })
+    }
+
+    /// How many of my numbers are winning numbers.
+    pub fn matches(&self) -> usize {
+        let winning: HashSet<_> = self.winning.iter().collect();
+
+        self.mine.iter().filter(|x| winning.contains(x)).count()
+    }
+}
+
+#[test]
+fn check_parse() {
+    let card = Scratchcard::parse("Card  12: 41 48 83 | 83 86  6 48").unwrap();
+    assert_eq!(
+        card,
+        Scratchcard {
+            id: 12,
+            winning: vec![41, 48, 83],
+            mine: vec![83, 86, 6, 48],
+        }
+    );
+    assert_eq!(card.matches(), 2);
+
+    let error = |line| {
+        let error = Scratchcard::parse(line).unwrap_err();
+        (error.column, error.message)
+    };
+    assert_eq!(
+        error("Card 1 41 | 41"),
+        (1, "expected 'Card <id>: '".into())
+    );
+    assert_eq!(error("Crd 1: 41 | 41"), (1, "expected 'Card <id>'".into()));
+    assert_eq!(error("Card x: 41 | 41"), (6, "bad card id".into()));
+    assert_eq!(
+        error("Card 1: 41 41"),
+        (8, "no '|' between the numbers".into())
+    );
+    assert_eq!(error("Card 1: 41 | 4x"), (14, "bad number".into()));
+    assert_eq!(
+        error("Card 1: 41 7 41 | 4"),
+        (14, "number given twice".into())
+    );
+}
@@ -0,0 +1,40 @@
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day4};
+
+/// Five winning numbers and eight of mine, of which the first `matches` are
+/// winning ones.
+fn card(numbers: &[u32], matches: usize) -> String {
+    let (winning, rest) = numbers.split_at(5);
+    let mine = winning[..matches].iter().chain(&rest[..8 - matches]);
+
+    let show = |x: &u32| format!("{:>2}", x);
+    let winning: Vec<_> = winning.iter().map(show).collect();
+    let mine: Vec<_> = mine.map(show).collect();
+    format!("{} | {}", winning.join(" "), mine.join(" "))
+}
+
+/// Cards which never win copies of cards past the end of the table.
+pub fn input() -> impl Strategy<Value = String> {
+    let pool: Vec<u32> = (1..=40).collect();
+    let numbers = prop::sample::subsequence(pool, 13).prop_shuffle();
+
+    prop::collection::vec((numbers, any::<prop::sample::Index>()), 1..=8).prop_map(|cards| {
+        let lines: Vec<_> = cards
+            .iter()
+            .enumerate()
+            .map(|(idx, (numbers, matches))| {
+                let matches = matches.index((cards.len() - idx).min(6));
+                format!("Card {}: {}", idx + 1, card(numbers, matches))
+            })
+            .collect();
+        lines.join("\n")
+    })
+}
+
+common::reference_tests! {
+    Day4;
+    part_one: input();
+    part_two: input();
+}
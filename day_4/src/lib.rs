@@ -0,0 +1,164 @@
+use common::{Error, ParseError, Solution};
+
+mod card;
+mod scoring;
+mod simulation;
+
+pub use card::Scratchcard;
+pub use scoring::{Doubling, Fibonacci, Linear, Scoring};
+pub use simulation::{simulate, Event, Overflow};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+static TEST_INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+fn first_task(matches: &[usize], scoring: &impl Scoring) -> Result<u64, Error> {
+    matches
+        .iter()
+        .try_fold(0u64, |acc, &x| acc.checked_add(scoring.score(x)?))
+        .ok_or(Error::NoSolution("the points don't fit in a u64"))
+}
+
+#[test]
+fn check_first_task() {
+    assert_eq!(Day4::solve_part_one(TEST_INPUT), Ok(13));
+}
+
+fn second_task(matches: &[usize], overflow: Overflow) -> Result<u64, Error> {
+    simulate(matches, overflow, |_| {})?
+        .iter()
+        .try_fold(0u64, |acc, &x| acc.checked_add(x))
+        .ok_or(Error::NoSolution("there are more cards than fit in a u64"))
+}
+
+#[test]
+fn check_second_task() {
+    assert_eq!(Day4::solve_part_two(TEST_INPUT), Ok(30));
+
+    // each card doubles the copies of those after it, so the last has 2^63
+    // and, with a first card which wins nothing, there are 2^64 in all
+    let card = |id: u32, matches: u32| {
+        let numbers: Vec<_> = (1..=matches).map(|x| x.to_string()).collect();
+        format!("Card {}: {1} | {1}", id, numbers.join(" "))
+    };
+    let cards: Vec<_> = (1..=65).map(|x| card(x, (65 - x) % 64)).collect();
+    let day = Day4::parse(&cards.join("\n")).unwrap();
+    assert_eq!(day.copies(Overflow::Error).unwrap()[64], 1 << 63);
+    assert_eq!(
+        day.part_two(),
+        Err(Error::NoSolution("there are more cards than fit in a u64"))
+    );
+
+    let cards: Vec<_> = (1..=120).map(|x| card(x, (120 - x).min(5))).collect();
+    assert_eq!(
+        Day4::solve_part_two(&cards.join("\n")),
+        Err(Error::NoSolution(
+            "there are more copies of a card than fit in a u64"
+        ))
+    );
+}
+
+#[test]
+fn check_copies() {
+    let day = Day4::parse(TEST_INPUT).unwrap();
+    assert_eq!(day.copies(Overflow::Error), Ok(vec![1, 2, 4, 8, 14, 1]));
+
+    let trace = day.trace(Overflow::Error).unwrap();
+    assert_eq!(trace.len(), 4 + 2 + 2 + 1);
+    assert_eq!(
+        trace[6],
+        Event {
+            card: 3,
+            won: 4,
+            copies: 4
+        }
+    );
+}
+
+#[test]
+fn check_scoring() {
+    let day = Day4::parse(TEST_INPUT).unwrap();
+    assert_eq!(day.cards()[2].id, 3);
+    assert_eq!(day.score(&Linear), Ok(4 + 2 + 2 + 1));
+    assert_eq!(day.score(&Fibonacci), Ok(3 + 1 + 1 + 1));
+    assert_eq!(day.score(&|x| (x > 0) as u64), Ok(4));
+
+    // 64 matches is worth 2^63 points, which only fits in a u64 once
+    let numbers: Vec<_> = (1..=64).map(|x| x.to_string()).collect();
+    let card = format!("Card 1: {0} | {0}", numbers.join(" "));
+    assert_eq!(Day4::solve_part_one(&card), Ok(1 << 63));
+    assert_eq!(
+        Day4::solve_part_one(&format!("{}\n{}", card, card.replace("Card 1", "Card 2"))),
+        Err(Error::NoSolution("the points don't fit in a u64"))
+    );
+}
+
+pub struct Day4 {
+    cards: Vec<Scratchcard>,
+    matches: Vec<usize>,
+}
+
+impl Day4 {
+    pub fn cards(&self) -> &[Scratchcard] {
+        &self.cards
+    }
+
+    /// The total points the cards are worth under `scoring`.
+    pub fn score(&self, scoring: &impl Scoring) -> Result<u64, Error> {
+        first_task(&self.matches, scoring)
+    }
+
+    /// How many cards there are in all once they've all been scratched.
+    pub fn total_cards(&self, overflow: Overflow) -> Result<u64, Error> {
+        second_task(&self.matches, overflow)
+    }
+
+    /// How many of each card there are once they've all been scratched.
+    pub fn copies(&self, overflow: Overflow) -> Result<Vec<u64>, Error> {
+        simulate(&self.matches, overflow, |_| {})
+    }
+
+    /// Every time a card won copies of others, in order.
+    pub fn trace(&self, overflow: Overflow) -> Result<Vec<Event>, Error> {
+        let mut events = vec![];
+        simulate(&self.matches, overflow, |x| events.push(x))?;
+
+        Ok(events)
+    }
+}
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let cards: Vec<_> = input
+            .lines()
+            .map(|line| Scratchcard::parse(line).map_err(|e| e.within(input, line)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day4 {
+            matches: cards.iter().map(Scratchcard::matches).collect(),
+            cards,
+        })
+    }
+
+    fn part_one(&self) -> Result<u64, Error> {
+        self.score(&Doubling)
+    }
+
+    fn part_two(&self) -> Result<u64, Error> {
+        self.total_cards(Overflow::Error)
+    }
+}
@@ -1,58 +1,58 @@
-use std::collections::HashSet;
-
-const INPUT: &str = include_str!("input.txt");
-
-fn matching_numbers() -> impl Iterator<Item = usize> {
-    INPUT.lines().map(|line| {
-        let round = line.split(":").nth(1).unwrap();
-        let mut x = round.split("|").map(|x| x.trim());
-        let winning: HashSet<u32> = x
-            .next()
-            .unwrap()
-            .split_whitespace()
-            .map(|x| x.parse())
-            .flatten()
-            .collect();
-        let mine: HashSet<u32> = x
-            .next()
-            .unwrap()
-            .split_whitespace()
-            .map(|x| x.parse())
-            .flatten()
-            .collect();
-
-        winning.intersection(&mine).count()
-    })
-}
-
-fn first_task() {
-    let score: u64 = matching_numbers()
-        .map(|x| match x {
-            0 => 0,
-            x => 2_u64.pow(x as u32 - 1),
-        })
-        .sum();
-
-    dbg!(score);
+use clap::{
+    builder::{PossibleValuesParser, TypedValueParser},
+    Parser,
+};
+use common::{exit, Solution};
+use day_4::{Day4, Overflow};
+
+/// Solves day 4, optionally with another policy for part 2.
+#[derive(Parser)]
+struct Args {
+    /// Input file, or `-` for stdin. Defaults to `inputs/day_4.txt`
+    input: Option<String>,
+    /// What to do with cards which win copies past the end of the table
+    #[arg(
+        long,
+        default_value = "error",
+        value_parser = PossibleValuesParser::new(Overflow::NAMES)
+            .map(|x: String| Overflow::named(&x).unwrap()),
+    )]
+    overflow: Overflow,
+    /// Print which card won which copies, followed by how many of each card
+    /// there are
+    #[arg(long)]
+    trace: bool,
 }
 
-fn second_task() {
-    let matches: Vec<_> = matching_numbers().collect();
-    let mut total_cards = vec![0; matches.len()];
-    for idx in (0..matches.len()).rev() {
-        let cards_won = matches[idx];
-        total_cards[idx] = 1;
-        for widx in 1..=cards_won {
-            total_cards[idx] += total_cards[widx + idx];
+fn main() {
+    let Args {
+        input,
+        overflow,
+        trace,
+    } = Args::parse();
+
+    let input = common::input::read(Day4::DAY, input.as_deref()).unwrap_or_else(|e| exit(e));
+    let day =
+        Day4::parse(&common::normalise(&input)).unwrap_or_else(|e| exit(e.for_day(Day4::DAY)));
+
+    if trace {
+        for event in day.trace(overflow).unwrap_or_else(|e| exit(e)) {
+            println!("{}", event);
         }
     }
-
-    let total: usize = total_cards.iter().sum();
-
-    dbg!(total);
-}
-
-fn main() {
-    first_task();
-    second_task();
+    match day.part_one() {
+        Ok(answer) => println!("part 1: {}", answer),
+        Err(e) => eprintln!("part 1: {}", e),
+    }
+    if trace {
+        if let Ok(copies) = day.copies(overflow) {
+            for (card, copies) in (1..).zip(copies.iter()) {
+                println!("card {}: {}", card, copies);
+            }
+        }
+    }
+    match day.total_cards(overflow) {
+        Ok(answer) => println!("part 2: {}", answer),
+        Err(e) => eprintln!("part 2: {}", e),
+    }
 }
@@ -0,0 +1,30 @@
+use crate::{Day4, Scratchcard};
+
+fn matches(card: &Scratchcard) -> usize {
+    card.mine
+        .iter()
+        .filter(|x| card.winning.iter().any(|y| y == *x))
+        .count()
+}
+
+pub fn part_one(day: &Day4) -> u64 {
+    day.cards
+        .iter()
+        .map(|card| {
+            (0..matches(card)).fold(0, |points, _| if points == 0 { 1 } else { points * 2 })
+        })
+        .sum()
+}
+
+/// Scratches every copy of every card one at a time.
+pub fn part_two(day: &Day4) -> u64 {
+    let mut pile: Vec<usize> = (0..day.cards.len()).collect();
+    let mut count = 0;
+
+    while let Some(card) = pile.pop() {
+        count += 1;
+        pile.extend(card + 1..=card + matches(&day.cards[card]));
+    }
+
+    count
+}
@@ -0,0 +1,70 @@
+/// How many points a card is worth, given how many of its numbers matched,
+/// or `None` if that doesn't fit in a `u64`.
+pub trait Scoring {
+    fn score(&self, matches: usize) -> Option<u64>;
+}
+
+/// The puzzle's scoring: one point for the first match, doubled for every
+/// match after that.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Doubling;
+
+impl Scoring for Doubling {
+    fn score(&self, matches: usize) -> Option<u64> {
+        match matches {
+            0 => Some(0),
+            x => 1u64.checked_shl(u32::try_from(x - 1).ok()?),
+        }
+    }
+}
+
+/// One point per match.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Linear;
+
+impl Scoring for Linear {
+    fn score(&self, matches: usize) -> Option<u64> {
+        Some(matches as u64)
+    }
+}
+
+/// The `n`th Fibonacci number for `n` matches, so 1, 1, 2, 3, 5...
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Fibonacci;
+
+impl Scoring for Fibonacci {
+    fn score(&self, matches: usize) -> Option<u64> {
+        // The next number only matters if there's another match.
+        (0..matches)
+            .try_fold((0u64, Some(1u64)), |(a, b), _| {
+                let b = b?;
+                Some((b, a.checked_add(b)))
+            })
+            .map(|(a, _)| a)
+    }
+}
+
+impl<F: Fn(usize) -> u64> Scoring for F {
+    fn score(&self, matches: usize) -> Option<u64> {
+        Some(self(matches))
+    }
+}
+
+#[test]
+fn check_scoring() {
+    let scores = |scoring: &dyn Scoring| {
+        (0..6)
+            .map(|x| scoring.score(x).unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(scores(&Doubling), [0, 1, 2, 4, 8, 16]);
+    assert_eq!(scores(&Linear), [0, 1, 2, 3, 4, 5]);
+    assert_eq!(scores(&Fibonacci), [0, 1, 1, 2, 3, 5]);
+    assert_eq!(scores(&|x| x as u64 * 10), [0, 10, 20, 30, 40, 50]);
+
+    assert_eq!(Doubling.score(64), Some(1 << 63));
+    assert_eq!(Doubling.score(65), None);
+    assert_eq!(Fibonacci.score(93), Some(12200160415121876738));
+    assert_eq!(Fibonacci.score(94), None);
+}
@@ -0,0 +1,138 @@
+use std::fmt::Display;
+
+use common::Error;
+
+/// What to do when a card wins copies of cards past the end of the table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// Only win the cards which exist.
+    Clamp,
+    /// Give up with an error.
+    #[default]
+    Error,
+    /// Carry on from the first card. Copies won this way are counted, but
+    /// as those cards have already been scratched they don't win anything.
+    Wrap,
+}
+
+impl Overflow {
+    pub const NAMES: &'static [&'static str] = &["clamp", "error", "wrap"];
+
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "clamp" => Some(Self::Clamp),
+            "error" => Some(Self::Error),
+            "wrap" => Some(Self::Wrap),
+            _ => None,
+        }
+    }
+}
+
+/// The copies of card `card` winning `copies` copies of card `won`. Cards
+/// are numbered from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    pub card: usize,
+    pub won: usize,
+    pub copies: u64,
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "card {} won {} {} of card {}",
+            self.card,
+            self.copies,
+            if self.copies == 1 { "copy" } else { "copies" },
+            self.won
+        )
+    }
+}
+
+/// Scratches every card in order, given how many numbers each one matched,
+/// and returns how many of each card there are at the end, originals
+/// included. `on_event` is called each time a card wins copies.
+pub fn simulate(
+    matches: &[usize],
+    overflow: Overflow,
+    mut on_event: impl FnMut(Event),
+) -> Result<Vec<u64>, Error> {
+    let mut copies = vec![1u64; matches.len()];
+
+    for (card, &won) in matches.iter().enumerate() {
+        // Taken up front, since with wrapping a card can win copies of
+        // itself.
+        let n = copies[card];
+        for won in card + 1..=card + won {
+            let won = match overflow {
+                _ if won < matches.len() => won,
+                Overflow::Clamp => break,
+                Overflow::Error => {
+                    return Err(Error::NoSolution(
+                        "a card wins copies past the end of the table",
+                    ))
+                }
+                Overflow::Wrap => won % matches.len(),
+            };
+
+            copies[won] = copies[won].checked_add(n).ok_or(Error::NoSolution(
+                "there are more copies of a card than fit in a u64",
+            ))?;
+            on_event(Event {
+                card: card + 1,
+                won: won + 1,
+                copies: n,
+            });
+        }
+    }
+
+    Ok(copies)
+}
+
+#[test]
+fn check_simulate() {
+    let matches = [2, 0, 2];
+
+    let mut events = vec![];
+    assert_eq!(
+        simulate(&matches, Overflow::Wrap, |x| events.push(x.to_string())),
+        Ok(vec![3, 4, 2])
+    );
+    assert_eq!(
+        events,
+        [
+            "card 1 won 1 copy of card 2",
+            "card 1 won 1 copy of card 3",
+            "card 3 won 2 copies of card 1",
+            "card 3 won 2 copies of card 2",
+        ]
+    );
+
+    assert_eq!(
+        simulate(&matches, Overflow::Clamp, |_| {}),
+        Ok(vec![1, 2, 2])
+    );
+    assert!(simulate(&matches, Overflow::Error, |_| {}).is_err());
+
+    // every card winning the next five doubles the copies about as often
+    let matches: Vec<_> = (0..120).map(|x| (119 - x).min(5)).collect();
+    assert_eq!(
+        simulate(&matches, Overflow::Error, |_| {}),
+        Err(Error::NoSolution(
+            "there are more copies of a card than fit in a u64"
+        ))
+    );
+
+    let card = crate::Scratchcard::parse("Card 1: 5 6 | 5 6").unwrap();
+    let mut events = vec![];
+    assert_eq!(
+        simulate(&[card.matches()], Overflow::Wrap, |x| events
+            .push(x.to_string())),
+        Ok(vec![3])
+    );
+    assert_eq!(
+        events,
+        ["card 1 won 1 copy of card 1", "card 1 won 1 copy of card 1"]
+    );
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+use common::Solution;
+use proptest::prelude::*;
+
+use crate::CATEGORIES;
+#[cfg(test)]
+use crate::{reference, Day5, Map};
+
+/// Lays out segments of the given sizes one after another in `order`, with
+/// a gap before each, giving where each segment starts.
+fn layout(sizes: &[u64], gaps: &[u64], order: &[usize]) -> Vec<u64> {
+    let mut starts = vec![0; sizes.len()];
+    let mut position = 0;
+    for (&idx, &gap) in order.iter().zip(gaps) {
+        starts[idx] = position + gap;
+        position = starts[idx] + sizes[idx];
+    }
+
+    starts
+}
+
+/// The `destination source size` lines of a map. Neither the sources nor
+/// the destinations overlap each other.
+fn map() -> impl Strategy<Value = Vec<String>> {
+    (1..5_usize)
+        .prop_flat_map(|count| {
+            (
+                prop::collection::vec(1..15_u64, count),
+                prop::collection::vec(0..10_u64, count),
+                prop::collection::vec(0..10_u64, count),
+                Just((0..count).collect::<Vec<_>>()).prop_shuffle(),
+                Just((0..count).collect::<Vec<_>>()).prop_shuffle(),
+            )
+        })
+        .prop_map(
+            |(sizes, source_gaps, dest_gaps, source_order, dest_order)| {
+                let sources = layout(&sizes, &source_gaps, &source_order);
+                let dests = layout(&sizes, &dest_gaps, &dest_order);
+
+                (0..sizes.len())
+                    .map(|idx| format!("{} {} {}", dests[idx], sources[idx], sizes[idx]))
+                    .collect()
+            },
+        )
+}
+
+pub fn input() -> impl Strategy<Value = String> {
+    (
+        prop::collection::vec((0..100_u64, 1..10_u64), 1..4),
+        prop::collection::vec(map(), 1..CATEGORIES.len()),
+    )
+        .prop_map(|(seeds, maps)| {
+            let seeds: Vec<_> = seeds
+                .iter()
+                .flat_map(|&(start, length)| [start.to_string(), length.to_string()])
+                .collect();
+            // Categories are skipped rather than the chain stopping short of
+            // locations.
+            let mut categories = CATEGORIES[..maps.len()].to_vec();
+            categories.push(CATEGORIES[CATEGORIES.len() - 1]);
+
+            let maps: Vec<_> = maps
+                .iter()
+                .enumerate()
+                .map(|(idx, lines)| {
+                    format!(
+                        "{}-to-{} map:\n{}",
+                        categories[idx],
+                        categories[idx + 1],
+                        lines.join("\n")
+                    )
+                })
+                .collect();
+
+            format!("seeds: {}\n\n{}", seeds.join(" "), maps.join("\n\n"))
+        })
+}
+
+common::reference_tests! {
+    Day5;
+    part_one: input();
+    part_two: input();
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn composed_maps_match_applying_each(
+        input in input(),
+        values in prop::collection::vec(0..200_u64, 10),
+    ) {
+        let day = Day5::parse(&input).unwrap();
+        let chain = day
+            .almanac()
+            .maps()
+            .iter()
+            .fold(Map::identity(), |chain, map| chain.compose(map));
+
+        for value in values {
+            let expected = day.almanac().maps().iter().fold(value, |x, map| map.apply(x));
+            prop_assert_eq!(chain.apply(value), expected);
+        }
+    }
+}
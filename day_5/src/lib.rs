@@ -1,262 +1,429 @@
-static INPUT: &str = include_str!("input.txt");
-
-#[derive(Clone, Copy, Debug)]
-struct Range {
-    dest_start: u64,
-    source_start: u64,
-    size: u64,
+use std::{
+    collections::{HashMap, VecDeque},
+    ops,
+};
+
+use common::{Error, ParseError, Solution};
+
+mod map;
+mod validate;
+
+pub use map::{merge, Map, Range};
+pub use validate::{validate, Problem, CATEGORIES};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+static TEST_INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+/// The source and destination categories in an `X-to-Y map:` header.
+fn parse_header(header: &str) -> Option<(&str, &str)> {
+    header.strip_suffix(" map:")?.split_once("-to-")
 }
 
-impl Range {
-    fn dest(&self) -> std::ops::Range<u64> {
-        self.dest_start..self.dest_start + self.size
-    }
-
-    fn source(&self) -> std::ops::Range<u64> {
-        self.source_start..self.source_start + self.size
-    }
-}
-
-#[derive(Clone, Debug)]
-struct Map {
-    ranges: Vec<Range>,
-}
+/// The maps, along with the categories each one goes from and to.
+fn make_maps_from_input(input: &str) -> Result<Vec<(String, String, Map)>, ParseError> {
+    let mut maps = Vec::new();
 
-impl Map {
-    fn make_from_input(input: &str) -> Self {
-        let mut ranges = Vec::new();
-        for line in input.lines() {
-            if line.chars().next().is_some_and(|x| !x.is_ascii_digit()) {
-                continue;
-            }
+    let mut seeds_and_maps = input.split("\n\n");
+    seeds_and_maps.next();
 
-            let mut range = line.split_whitespace();
-            let destination = range.next().unwrap().parse().unwrap();
-            let source = range.next().unwrap().parse().unwrap();
-            let size = range.next().unwrap().parse().unwrap();
-            ranges.push(Range {
-                dest_start: destination,
-                source_start: source,
-                size,
-            })
-        }
+    for map in seeds_and_maps {
+        let (header, ranges) = map.split_once('\n').unwrap_or((map, &map[map.len()..]));
+        let (source, destination) = parse_header(header).ok_or_else(|| {
+            ParseError::at(input, header, "expected '<source>-to-<destination> map:'")
+        })?;
+        let map = Map::make_from_input(ranges).map_err(|e| e.within(input, ranges))?;
 
-        Map { ranges }
+        maps.push((source.to_string(), destination.to_string(), map));
     }
 
-    fn destination_from_source(&self, source: u64) -> u64 {
-        for range in self.ranges.iter() {
-            if range.source().contains(&source) {
-                return range.dest_start + (source - range.source_start);
-            }
-        }
-
-        source
+    if maps.is_empty() {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "expected maps after the seeds",
+        ));
     }
 
-    fn fill_out(&mut self, min: u64, max: u64) {
-        self.ranges.sort_by_key(|x| x.source_start);
-
-        let mut extras = Vec::new();
-        for window in self.ranges.windows(2) {
-            let first = window[0];
-            let second = window[1];
-
-            if first.source_start + first.size < second.source_start {
-                let start = first.source_start + first.size;
-                let end = second.source_start;
-                let size = end - start;
-
-                extras.push(Range {
-                    source_start: start,
-                    size,
-                    dest_start: start,
-                })
-            }
-        }
-
-        if self.ranges[0].source_start > min {
-            let start = min;
-            let end = self.ranges[0].source_start;
-            let size = end - start;
-            extras.push(Range {
-                source_start: start,
-                size,
-                dest_start: start,
-            })
-        }
-
-        let l = &self.ranges[self.ranges.len() - 1];
-        if l.source_start + l.size < max {
-            let start = l.source_start + l.size;
-            let end = max;
-            let size = end - start;
-            extras.push(Range {
-                source_start: start,
-                size,
-                dest_start: start,
-            })
-        }
-
-        self.ranges.append(&mut extras);
-    }
+    Ok(maps)
 }
 
-fn make_maps_from_input(input: &str) -> Vec<Map> {
-    let mut maps = Vec::new();
+fn make_seeds_from_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    let seed_line = input.lines().next().unwrap_or_default();
+    let Some(seeds) = seed_line.strip_prefix("seeds:") else {
+        return Err(ParseError::at(input, seed_line, "expected 'seeds:'"));
+    };
 
-    let mut seeds_and_maps = input.split("\n\n");
-    seeds_and_maps.next();
+    let seeds = seeds
+        .split_whitespace()
+        .map(|x| x.parse().map_err(|_| ParseError::at(input, x, "bad seed")))
+        .collect::<Result<Vec<_>, _>>()?;
 
-    for map in seeds_and_maps {
-        maps.push(Map::make_from_input(map));
+    if seeds.is_empty() {
+        return Err(ParseError::at(input, seed_line, "no seeds"));
     }
 
-    maps
+    Ok(seeds)
 }
 
-fn make_seeds_from_input(input: &str) -> Vec<u64> {
-    let seed_line = input.lines().next().unwrap();
-
-    seed_line
-        .split_whitespace()
-        .map(|x| x.parse())
-        .flatten()
-        .collect()
+fn first_task(almanac: &Almanac) -> Option<u64> {
+    almanac
+        .convert("seed", "location", &almanac.seeds)?
+        .first()
+        .copied()
 }
 
 #[test]
-fn task_1() {
-    let seeds = make_seeds_from_input(INPUT);
-    let maps = make_maps_from_input(INPUT);
-
-    let mut sequence = seeds.clone();
+fn check_first_task() {
+    assert_eq!(Day5::solve_part_one(TEST_INPUT), Ok(35));
+}
 
-    for map in maps.iter() {
-        sequence
-            .iter_mut()
-            .for_each(|x| *x = map.destination_from_source(*x));
+fn second_task(almanac: &Almanac) -> Result<u64, Error> {
+    if !almanac.seeds.len().is_multiple_of(2) {
+        return Err(Error::NoSolution("the seeds don't pair up into ranges"));
     }
 
-    dbg!(&sequence);
-    dbg!(sequence.iter().min());
+    let seeds = almanac
+        .seeds
+        .chunks_exact(2)
+        .filter(|x| x[1] > 0)
+        .map(|x| Some(x[0]..=x[0].checked_add(x[1] - 1)?))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(Error::NoSolution(
+            "a seed range goes past 18446744073709551615",
+        ))?;
+
+    almanac
+        .convert_ranges("seed", "location", &seeds)
+        .and_then(|x| x.first().map(|x| *x.start()))
+        .ok_or(Error::NoSolution("no seed ranges lead to a location"))
 }
 
-fn ranges_overlap(
-    a: &std::ops::Range<u64>,
-    b: &std::ops::Range<u64>,
-) -> Option<std::ops::Range<u64>> {
-    let start = a.start.max(b.start);
-    let end = a.end.min(b.end);
-    if start < end {
-        Some(start..end)
-    } else {
-        None
-    }
+#[test]
+fn check_second_task() {
+    assert_eq!(Day5::solve_part_two(TEST_INPUT), Ok(46));
+    assert_eq!(
+        Day5::solve_part_two("seeds: 79 14 55\n\nseed-to-location map:\n1 2 3"),
+        Err(Error::NoSolution("the seeds don't pair up into ranges"))
+    );
+    assert_eq!(
+        Day5::solve_part_two("seeds: 18446744073709551615 2\n\nseed-to-location map:\n1 2 3"),
+        Err(Error::NoSolution(
+            "a seed range goes past 18446744073709551615"
+        ))
+    );
+}
+
+/// One map on the way between two categories, used in reverse if
+/// `backwards`.
+#[derive(Clone, Copy, Debug)]
+struct Step {
+    map: usize,
+    backwards: bool,
 }
 
-struct Seeds {
-    seeds: Vec<std::ops::Range<u64>>,
+/// The seeds to plant and the maps between categories, which are found by
+/// their `X-to-Y` names rather than the order they're in.
+#[derive(Clone, Debug)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Map>,
+    categories: Vec<(String, String)>,
 }
 
-impl Seeds {
-    fn make_from_input(input: &str) -> Self {
-        let seed_line = input.lines().next().unwrap();
+impl Almanac {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let (categories, maps) = make_maps_from_input(input)?
+            .into_iter()
+            .map(|(source, destination, map)| ((source, destination), map))
+            .unzip();
+
+        Ok(Almanac {
+            seeds: make_seeds_from_input(input)?,
+            maps,
+            categories,
+        })
+    }
 
-        let numbers: Vec<u64> = seed_line
-            .split_whitespace()
-            .map(|x| x.parse())
-            .flatten()
-            .collect();
+    pub fn seeds(&self) -> &[u64] {
+        &self.seeds
+    }
 
-        Self {
-            seeds: numbers.chunks_exact(2).map(|x| x[0]..x[0] + x[1]).collect(),
-        }
+    /// The maps in the order they're written.
+    pub fn maps(&self) -> &[Map] {
+        &self.maps
     }
-}
 
-fn find_seed_number_range_corresponding(
-    maps: &[Map],
-    destination_range: std::ops::Range<u64>,
-    seeds: &Seeds,
-) -> Option<u64> {
-    let Some((last, rest)) = maps.split_last() else {
-        // check the seeds
-        for seed in seeds.seeds.iter() {
-            if let Some(overlap) = ranges_overlap(seed, &destination_range) {
-                return Some(overlap.start);
+    /// The source and destination category of each map.
+    pub fn categories(&self) -> &[(String, String)] {
+        &self.categories
+    }
+
+    /// The fewest maps which lead from `from` to `to`, going against the
+    /// direction of a map where needed.
+    fn route(&self, from: &str, to: &str) -> Option<Vec<Step>> {
+        let mut previous: HashMap<&str, Option<(&str, Step)>> = HashMap::from([(from, None)]);
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                let mut steps = vec![];
+                let mut category = to;
+                while let Some((before, step)) = previous[category] {
+                    steps.push(step);
+                    category = before;
+                }
+                steps.reverse();
+
+                return Some(steps);
+            }
+
+            for (map, (source, destination)) in self.categories.iter().enumerate() {
+                let (next, backwards) = if source == category {
+                    (destination.as_str(), false)
+                } else if destination == category {
+                    (source.as_str(), true)
+                } else {
+                    continue;
+                };
+
+                if !previous.contains_key(next) {
+                    previous.insert(next, Some((category, Step { map, backwards })));
+                    queue.push_back(next);
+                }
             }
         }
 
-        return None;
-    };
+        None
+    }
 
-    for range in last.ranges.iter() {
-        if let Some(overlap) = ranges_overlap(&range.dest(), &destination_range) {
-            let start = overlap.start - range.dest_start + range.source_start;
-            let size = overlap.end - overlap.start;
-            let overlapping_source_range = start..start + size;
-
-            // dbg!(
-            //     &range,
-            //     &destination_range,
-            //     &overlap,
-            //     &overlapping_source_range
-            // );
-
-            if let Some(result) =
-                find_seed_number_range_corresponding(rest, overlapping_source_range, seeds)
-            {
-                return Some(result);
+    /// Where the values in `ranges` of category `from` end up in category
+    /// `to`, as sorted ranges which neither overlap nor touch. `None` if no
+    /// maps connect the two.
+    pub fn convert_ranges(
+        &self,
+        from: &str,
+        to: &str,
+        ranges: &[ops::RangeInclusive<u64>],
+    ) -> Option<Vec<ops::RangeInclusive<u64>>> {
+        let route = self.route(from, to)?;
+
+        Some(route.iter().fold(merge(ranges.to_vec()), |ranges, step| {
+            let map = &self.maps[step.map];
+            if step.backwards {
+                map.preimage(&ranges)
+            } else {
+                map.image(&ranges)
             }
-        }
+        }))
     }
 
-    return None;
-}
+    /// The sorted values in category `to` which `values` in category `from`
+    /// correspond to. Going against a map a value can come from several
+    /// values, or none.
+    pub fn convert(&self, from: &str, to: &str, values: &[u64]) -> Option<Vec<u64>> {
+        let ranges: Vec<_> = values.iter().map(|&x| x..=x).collect();
+
+        Some(
+            self.convert_ranges(from, to, &ranges)?
+                .into_iter()
+                .flatten()
+                .collect(),
+        )
+    }
 
-#[test]
-fn task_2() {
-    let mut maps = make_maps_from_input(INPUT);
+    /// The seeds whose value in `category` is in one of `ranges`.
+    pub fn seeds_in(
+        &self,
+        category: &str,
+        ranges: &[ops::RangeInclusive<u64>],
+    ) -> Option<Vec<u64>> {
+        let seeds = self.convert_ranges(category, "seed", ranges)?;
 
-    let max = maps
-        .iter()
-        .map(|x| {
-            x.ranges
+        Some(
+            self.seeds
                 .iter()
-                .map(|x| x.source_start.min(x.dest_start))
-                .max()
-        })
-        .flatten()
-        .max()
-        .unwrap();
+                .copied()
+                .filter(|x| seeds.iter().any(|seeds| seeds.contains(x)))
+                .collect(),
+        )
+    }
 
-    let mut seeds = Seeds::make_from_input(INPUT);
-    seeds.seeds.sort_by_key(|x| x.start);
+    /// The lowest location any of the seeds is planted in, if the maps lead
+    /// from seeds to locations.
+    pub fn lowest_location(&self) -> Option<u64> {
+        first_task(self)
+    }
 
-    let max = max.max(seeds.seeds.iter().map(|x| x.end).max().unwrap());
-    let min = 0;
+    /// The lowest location for the seeds when they're read as pairs of
+    /// start and length. It's an error if they don't pair up, if a range
+    /// goes past `u64::MAX`, or if none of them lead to a location.
+    pub fn lowest_location_for_ranges(&self) -> Result<u64, Error> {
+        second_task(self)
+    }
+}
 
-    maps.iter_mut().for_each(|x| x.fill_out(min, max));
+#[test]
+fn check_almanac() {
+    let almanac = Almanac::parse(TEST_INPUT).unwrap();
+    assert_eq!(almanac.seeds(), [79, 14, 55, 13]);
+    assert_eq!(almanac.maps().len(), 7);
+    assert_eq!(almanac.lowest_location(), Some(35));
+    assert_eq!(almanac.lowest_location_for_ranges(), Ok(46));
+
+    let almanac = Almanac::parse("seeds: 7\n\nseed-to-location map:\n1 2 3").unwrap();
+    assert_eq!(almanac.lowest_location(), Some(7));
+    assert!(almanac.lowest_location_for_ranges().is_err());
+
+    let error = Almanac::parse("seeds: 7\n\nseed-to-location:\n1 2 3").unwrap_err();
+    assert_eq!((error.line, error.column), (3, 1));
+    let error = Almanac::parse("seeds: 7\n\nseed-to-location map:").unwrap_err();
+    assert_eq!((error.line, error.column), (3, 22));
+    assert_eq!(error.message, "map has no ranges");
+    let error = Almanac::parse("seeds: 7").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 9));
+    assert_eq!(error.message, "expected maps after the seeds");
+
+    // the same almanacs the validator finds overflowing ranges in
+    let input = "seeds: 7\n\nseed-to-location map:\n1 2 3\n18446744073709551615 5 2";
+    let error = Almanac::parse(input).unwrap_err();
+    assert_eq!((error.line, error.column), (5, 1));
+    assert_eq!(error.message, "range goes past 18446744073709551615");
+    assert_eq!(validate(input), [Problem::Overflow { line: 5 }]);
+
+    let almanac =
+        Almanac::parse("seeds: 5 1\n\nseed-to-location map:\n18446744073709551615 5 1").unwrap();
+    assert_eq!(
+        almanac.convert("seed", "location", &[5]),
+        Some(vec![u64::MAX])
+    );
+    assert_eq!(almanac.lowest_location_for_ranges(), Ok(u64::MAX));
+
+    let almanac = Almanac::parse(
+        "seeds: 18446744073709551615 1\n\nseed-to-location map:\n7 18446744073709551615 1",
+    )
+    .unwrap();
+    assert_eq!(
+        almanac.convert("seed", "location", &[u64::MAX]),
+        Some(vec![7])
+    );
+    assert_eq!(almanac.lowest_location_for_ranges(), Ok(7));
+}
 
-    for map in maps.iter_mut() {
-        map.ranges.sort_by_key(|x| x.dest_start);
+#[test]
+fn check_routes() {
+    let almanac = Almanac::parse(TEST_INPUT).unwrap();
+
+    assert_eq!(
+        almanac.convert("seed", "soil", &[79, 14, 55, 13]),
+        Some(vec![13, 14, 57, 81])
+    );
+    assert_eq!(
+        almanac.convert("soil", "seed", &[81, 57]),
+        Some(vec![55, 79])
+    );
+    assert_eq!(almanac.convert("seed", "colour", &[1]), None);
+
+    let seeds = almanac.convert("location", "seed", &[46]).unwrap();
+    assert!(seeds.contains(&82));
+    for seed in seeds {
+        assert_eq!(almanac.convert("seed", "location", &[seed]), Some(vec![46]));
+    }
+    for soil in almanac.convert("humidity", "soil", &[60]).unwrap() {
+        assert_eq!(almanac.convert("soil", "humidity", &[soil]), Some(vec![60]));
     }
+    assert_eq!(
+        almanac.seeds_in("location", &[0..=35, 43..=43]),
+        Some(vec![14, 13])
+    );
+
+    let (seeds, maps) = TEST_INPUT.split_once("\n\n").unwrap();
+    let mut maps: Vec<_> = maps.split("\n\n").collect();
+    maps.reverse();
+    let almanac = Almanac::parse(&format!("{}\n\n{}", seeds, maps.join("\n\n"))).unwrap();
+    assert_eq!(almanac.lowest_location(), Some(35));
+    assert_eq!(almanac.lowest_location_for_ranges(), Ok(46));
+}
 
-    let (last, split_maps) = maps.split_last().unwrap();
+#[test]
+fn check_no_route() {
+    let input = "seeds: 1 2\n\nseed-to-soil map:\n1 2 3";
+
+    assert_eq!(
+        Day5::solve_part_one(input),
+        Err(Error::NoSolution("no maps lead from seed to location"))
+    );
+    assert_eq!(
+        Day5::solve_part_two(input),
+        Err(Error::NoSolution("no seed ranges lead to a location"))
+    );
+}
 
-    for range in last.ranges.iter() {
-        if let Some(seed) = find_seed_number_range_corresponding(split_maps, range.source(), &seeds)
-        {
-            dbg!(seed);
-            let location = maps
-                .iter()
-                .fold(seed, |acc, v| v.destination_from_source(acc));
-            dbg!(location);
+pub struct Day5 {
+    almanac: Almanac,
+}
 
-            return;
-        }
+impl Day5 {
+    pub fn almanac(&self) -> &Almanac {
+        &self.almanac
+    }
+}
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day5 {
+            almanac: Almanac::parse(input)?,
+        })
     }
 
-    panic!("couldn't find solution")
+    fn part_one(&self) -> Result<u64, Error> {
+        self.almanac
+            .lowest_location()
+            .ok_or(Error::NoSolution("no maps lead from seed to location"))
+    }
+
+    fn part_two(&self) -> Result<u64, Error> {
+        self.almanac.lowest_location_for_ranges()
+    }
 }
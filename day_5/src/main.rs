@@ -0,0 +1,42 @@
+use clap::Parser;
+use common::{exit, Solution};
+use day_5::Day5;
+
+/// Solves day 5, or checks the almanac.
+#[derive(Parser)]
+struct Args {
+    /// Input file, or `-` for stdin. Defaults to `inputs/day_5.txt`
+    input: Option<String>,
+    /// List everything wrong with the almanac instead of solving it
+    #[arg(long)]
+    validate: bool,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let input = common::input::read(Day5::DAY, args.input.as_deref()).unwrap_or_else(|e| exit(e));
+    let input = common::normalise(&input);
+
+    if args.validate {
+        let problems = day_5::validate(&input);
+        for problem in problems.iter() {
+            println!("{}", problem);
+        }
+        if !problems.is_empty() {
+            std::process::exit(1);
+        }
+        println!("no problems found");
+        return;
+    }
+
+    let day = Day5::parse(&input).unwrap_or_else(|e| exit(e.for_day(Day5::DAY)));
+    match day.part_one() {
+        Ok(answer) => println!("part 1: {}", answer),
+        Err(e) => eprintln!("part 1: {}", e),
+    }
+    match day.part_two() {
+        Ok(answer) => println!("part 2: {}", answer),
+        Err(e) => eprintln!("part 2: {}", e),
+    }
+}
@@ -0,0 +1,478 @@
+use std::ops;
+
+use common::ParseError;
+
+/// One past the largest value, which is where ranges covering `u64::MAX`
+/// end.
+const END: u128 = u64::MAX as u128 + 1;
+
+/// `size` values starting at `source_start` go to the same number of values
+/// starting at `dest_start`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Range {
+    pub dest_start: u64,
+    pub source_start: u64,
+    pub size: u64,
+}
+
+impl Range {
+    /// Whether the source or destination runs past `u64::MAX`. Ending just
+    /// after it is fine.
+    pub fn overflows(&self) -> bool {
+        let last = self.size.saturating_sub(1);
+        self.source_start.checked_add(last).is_none() || self.dest_start.checked_add(last).is_none()
+    }
+}
+
+/// A [`Range`] with room for ends at [`END`], which is what the maps work
+/// with inside.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Segment {
+    dest_start: u128,
+    source_start: u128,
+    size: u128,
+}
+
+impl Segment {
+    /// The segment leaving the values in `source` where they are.
+    fn identity(source: ops::Range<u128>) -> Self {
+        Self {
+            dest_start: source.start,
+            source_start: source.start,
+            size: source.end - source.start,
+        }
+    }
+
+    fn source(&self) -> ops::Range<u128> {
+        self.source_start..self.source_start + self.size
+    }
+
+    fn dest(&self) -> ops::Range<u128> {
+        self.dest_start..self.dest_start + self.size
+    }
+
+    /// Where `value`, which has to be in the source, goes.
+    fn apply(&self, value: u128) -> u128 {
+        value - self.source_start + self.dest_start
+    }
+
+    /// The part of this segment whose source is within `source`.
+    fn restrict(&self, source: &ops::Range<u128>) -> Option<Segment> {
+        let start = self.source_start.max(source.start);
+        let end = self.source().end.min(source.end);
+
+        (start < end).then(|| Segment {
+            dest_start: self.apply(start),
+            source_start: start,
+            size: end - start,
+        })
+    }
+
+    /// The same segment going from its destination to its source.
+    fn reversed(&self) -> Segment {
+        Segment {
+            dest_start: self.source_start,
+            source_start: self.dest_start,
+            size: self.size,
+        }
+    }
+}
+
+impl From<Range> for Segment {
+    /// The range, cut short if its source or destination would run past
+    /// `u64::MAX`.
+    fn from(range: Range) -> Self {
+        let (dest_start, source_start) = (range.dest_start as u128, range.source_start as u128);
+
+        Self {
+            dest_start,
+            source_start,
+            size: (range.size as u128)
+                .min(END - source_start)
+                .min(END - dest_start),
+        }
+    }
+}
+
+impl From<Segment> for Range {
+    /// Only segments which don't cover every value fit, which is all of
+    /// them but the identity.
+    fn from(segment: Segment) -> Self {
+        Self {
+            dest_start: segment.dest_start as u64,
+            source_start: segment.source_start as u64,
+            size: segment.size as u64,
+        }
+    }
+}
+
+/// `values` as a half open range, which can end at [`END`].
+fn widen(values: &ops::RangeInclusive<u64>) -> ops::Range<u128> {
+    *values.start() as u128..*values.end() as u128 + 1
+}
+
+/// The inverse of [`widen`], for ranges which aren't empty.
+fn narrow(values: ops::Range<u128>) -> ops::RangeInclusive<u64> {
+    values.start as u64..=(values.end - 1) as u64
+}
+
+/// A piecewise linear map of `u64`s, made of ranges which each shift a
+/// stretch of values. Values outside every range map to themselves, and
+/// where ranges overlap the first one wins.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Map {
+    ranges: Vec<Range>,
+}
+
+impl Map {
+    pub fn new(ranges: Vec<Range>) -> Self {
+        Self { ranges }
+    }
+
+    /// The map which leaves every value where it is.
+    pub fn identity() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn make_from_input(input: &str) -> Result<Self, ParseError> {
+        let mut ranges = Vec::new();
+        for line in input.lines() {
+            let numbers = line
+                .split_whitespace()
+                .map(|x| {
+                    x.parse()
+                        .map_err(|_| ParseError::at(input, x, "bad number"))
+                })
+                .collect::<Result<Vec<u64>, _>>()?;
+            let &[destination, source, size] = numbers.as_slice() else {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "expected destination, source and size",
+                ));
+            };
+            let range = Range {
+                dest_start: destination,
+                source_start: source,
+                size,
+            };
+            if range.overflows() {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("range goes past {}", u64::MAX),
+                ));
+            }
+            ranges.push(range);
+        }
+
+        if ranges.is_empty() {
+            return Err(ParseError::new(input, "map has no ranges"));
+        }
+
+        Ok(Map { ranges })
+    }
+
+    pub fn ranges(&self) -> &[Range] {
+        &self.ranges
+    }
+
+    /// Where `value` goes. Ranges which would run past `u64::MAX`, which
+    /// only [`Map::new`] allows, stop there.
+    pub fn apply(&self, value: u64) -> u64 {
+        let value = value as u128;
+
+        self.ranges
+            .iter()
+            .map(|&x| Segment::from(x))
+            .find(|x| x.source().contains(&value))
+            .map_or(value, |x| x.apply(value)) as u64
+    }
+
+    /// The same map with its ranges sorted by source, none of them
+    /// overlapping or leaving values where they are, and neighbours which
+    /// shift by the same amount joined together. Ranges which would run
+    /// past `u64::MAX` are cut short.
+    pub fn normalised(&self) -> Self {
+        let mut segments: Vec<Segment> = vec![];
+        for range in self.ranges.iter() {
+            let range = Segment::from(*range);
+
+            // Only the parts no earlier range covers.
+            let mut start = range.source_start;
+            let mut parts = vec![];
+            for other in segments.iter() {
+                if other.source_start >= range.source().end {
+                    break;
+                }
+                parts.extend(range.restrict(&(start..other.source_start)));
+                start = start.max(other.source().end);
+            }
+            parts.extend(range.restrict(&(start..range.source().end)));
+
+            segments.extend(parts);
+            segments.sort_by_key(|x| x.source_start);
+        }
+
+        Self::joined(segments)
+    }
+
+    /// The map made of `segments`, which have to be sorted by source and
+    /// not overlap, leaving out the ones which map values to themselves and
+    /// joining neighbours which shift by the same amount.
+    fn joined(segments: impl IntoIterator<Item = Segment>) -> Self {
+        let mut joined: Vec<Segment> = vec![];
+        for segment in segments
+            .into_iter()
+            .filter(|x| x.source_start != x.dest_start)
+        {
+            match joined.last_mut() {
+                Some(last)
+                    if last.source().end == segment.source_start
+                        && last.dest().end == segment.dest_start =>
+                {
+                    last.size += segment.size
+                }
+                _ => joined.push(segment),
+            }
+        }
+
+        Self {
+            ranges: joined.into_iter().map(Range::from).collect(),
+        }
+    }
+
+    /// Every value, split into segments in order of source, including the
+    /// ones which map values to themselves. The map has to be normalised.
+    fn segments(&self) -> Vec<Segment> {
+        let mut segments = vec![];
+        let mut start = 0;
+        for range in self.ranges.iter().map(|&x| Segment::from(x)) {
+            if start < range.source_start {
+                segments.push(Segment::identity(start..range.source_start));
+            }
+            segments.push(range);
+            start = range.source().end;
+        }
+        if start < END {
+            segments.push(Segment::identity(start..END));
+        }
+
+        segments
+    }
+
+    /// The map which applies this one and then `next`.
+    pub fn compose(&self, next: &Map) -> Map {
+        let next = next.normalised().segments();
+
+        let mut segments = vec![];
+        for segment in self.normalised().segments() {
+            let dest = segment.dest();
+            let from = next.partition_point(|x| x.source().end <= dest.start);
+            for part in next[from..]
+                .iter()
+                .take_while(|x| x.source_start < dest.end)
+                .filter_map(|x| x.restrict(&dest))
+            {
+                segments.push(Segment {
+                    source_start: segment.source_start + (part.source_start - dest.start),
+                    ..part
+                });
+            }
+        }
+
+        Map::joined(segments)
+    }
+
+    /// The map taking every value back to where it came from, if each one
+    /// came from exactly one value.
+    pub fn invert(&self) -> Option<Map> {
+        let mut segments = self.normalised().segments();
+        segments.sort_by_key(|x| x.dest_start);
+
+        // The sources cover every value, so the destinations have to as well.
+        let mut end = 0;
+        for segment in segments.iter() {
+            if segment.dest_start != end {
+                return None;
+            }
+            end = segment.dest().end;
+        }
+
+        Some(Map::joined(segments.iter().map(Segment::reversed)))
+    }
+
+    /// Where the values in `sources` go, as sorted ranges which neither
+    /// overlap nor touch.
+    pub fn image(&self, sources: &[ops::RangeInclusive<u64>]) -> Vec<ops::RangeInclusive<u64>> {
+        let segments = self.normalised().segments();
+
+        let mut image = vec![];
+        for source in sources.iter().map(widen) {
+            let from = segments.partition_point(|x| x.source().end <= source.start);
+            image.extend(
+                segments[from..]
+                    .iter()
+                    .take_while(|x| x.source_start < source.end)
+                    .filter_map(|x| x.restrict(&source))
+                    .map(|x| narrow(x.dest())),
+            );
+        }
+
+        merge(image)
+    }
+
+    /// The values which go somewhere in `dests`, as sorted ranges which
+    /// neither overlap nor touch.
+    pub fn preimage(&self, dests: &[ops::RangeInclusive<u64>]) -> Vec<ops::RangeInclusive<u64>> {
+        // The segments the other way round, which can overlap each other.
+        let mut reversed: Vec<_> = self
+            .normalised()
+            .segments()
+            .iter()
+            .map(Segment::reversed)
+            .collect();
+        reversed.sort_by_key(|x| x.source_start);
+
+        let mut preimage = vec![];
+        for dest in dests.iter().map(widen) {
+            preimage.extend(
+                reversed
+                    .iter()
+                    .take_while(|x| x.source_start < dest.end)
+                    .filter_map(|x| x.restrict(&dest))
+                    .map(|x| narrow(x.dest())),
+            );
+        }
+
+        merge(preimage)
+    }
+}
+
+/// Sorts `ranges` and joins the ones which overlap or touch, dropping any
+/// which are empty.
+pub fn merge(mut ranges: Vec<ops::RangeInclusive<u64>>) -> Vec<ops::RangeInclusive<u64>> {
+    ranges.retain(|x| !x.is_empty());
+    ranges.sort_by_key(|x| *x.start());
+
+    let mut merged: Vec<ops::RangeInclusive<u64>> = vec![];
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start().saturating_sub(1) <= *last.end() => {
+                *last = *last.start()..=*last.end().max(range.end())
+            }
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+fn range(dest_start: u64, source_start: u64, size: u64) -> Range {
+    Range {
+        dest_start,
+        source_start,
+        size,
+    }
+}
+
+#[test]
+fn check_normalised() {
+    let map = Map::new(vec![
+        range(20, 10, 10),
+        range(0, 15, 10),
+        range(30, 30, 5),
+        range(40, 20, 2),
+    ]);
+
+    assert_eq!(
+        map.normalised().ranges(),
+        [range(20, 10, 10), range(5, 20, 5)]
+    );
+    assert_eq!(
+        Map::new(vec![range(7, 2, 3), range(5, 0, 2)])
+            .normalised()
+            .ranges(),
+        [range(5, 0, 5)]
+    );
+    assert_eq!(
+        Map::new(vec![range(u64::MAX - 1, 0, 10)])
+            .normalised()
+            .ranges(),
+        [range(u64::MAX - 1, 0, 2)]
+    );
+    for value in 0..40 {
+        assert_eq!(map.normalised().apply(value), map.apply(value));
+    }
+}
+
+#[test]
+fn check_compose() {
+    let first = Map::new(vec![range(50, 98, 2), range(52, 50, 48)]);
+    let second = Map::new(vec![range(0, 15, 37), range(37, 52, 2), range(39, 0, 15)]);
+    let composed = first.compose(&second);
+
+    for value in 0..120 {
+        assert_eq!(composed.apply(value), second.apply(first.apply(value)));
+    }
+    assert_eq!(Map::identity().compose(&first), first.normalised());
+}
+
+#[test]
+fn check_invert() {
+    let map = Map::new(vec![range(50, 98, 2), range(52, 50, 48)]);
+    let inverse = map.invert().unwrap();
+
+    for value in 0..120 {
+        assert_eq!(inverse.apply(map.apply(value)), value);
+    }
+    assert_eq!(Map::new(vec![range(0, 10, 5)]).invert(), None);
+
+    // swaps the values either side of 2^63
+    let map = Map::new(vec![range(0, 1 << 63, 1 << 63), range(1 << 63, 0, 1 << 63)]);
+    assert_eq!(map.invert(), Some(map.normalised()));
+    assert_eq!(map.compose(&map), Map::identity());
+}
+
+#[test]
+fn check_image() {
+    let map = Map::new(vec![range(50, 98, 2), range(52, 50, 48)]);
+
+    assert_eq!(map.image(&[79..=92, 55..=67]), [57..=69, 81..=94]);
+    assert_eq!(map.image(&[96..=99, 0..=1]), [0..=1, 50..=51, 98..=99]);
+    assert_eq!(
+        merge(vec![
+            5..=7,
+            1..=2,
+            3..=3,
+            6..=6,
+            ops::RangeInclusive::new(9, 8)
+        ]),
+        [1..=3, 5..=7]
+    );
+    assert_eq!(merge(vec![0..=u64::MAX, 5..=6]), [0..=u64::MAX]);
+
+    // ranges reaching the largest value
+    let map = Map::new(vec![range(u64::MAX, 5, 1)]);
+    assert_eq!(map.apply(5), u64::MAX);
+    assert_eq!(map.image(&[5..=5]), [u64::MAX..=u64::MAX]);
+    assert_eq!(map.image(&[u64::MAX..=u64::MAX]), [u64::MAX..=u64::MAX]);
+    assert_eq!(map.image(&[0..=u64::MAX]), [0..=4, 6..=u64::MAX]);
+    assert_eq!(Map::identity().image(&[0..=u64::MAX]), [0..=u64::MAX]);
+}
+
+#[test]
+fn check_preimage() {
+    let map = Map::new(vec![range(50, 98, 2), range(52, 50, 48)]);
+    assert_eq!(map.preimage(&[57..=69, 81..=94]), [55..=67, 79..=92]);
+
+    let map = Map::new(vec![range(0, 10, 5)]);
+    assert_eq!(map.preimage(&[3..=3, 12..=12]), [3..=3, 13..=13]);
+
+    let map = Map::new(vec![range(u64::MAX, 5, 1)]);
+    assert_eq!(
+        map.preimage(&[u64::MAX..=u64::MAX]),
+        [5..=5, u64::MAX..=u64::MAX]
+    );
+}
@@ -0,0 +1,34 @@
+use crate::{Day5, Map};
+
+fn apply(map: &Map, value: u64) -> u64 {
+    map.ranges()
+        .iter()
+        .find(|x| x.source_start <= value && value < x.source_start + x.size)
+        .map_or(value, |x| x.dest_start + (value - x.source_start))
+}
+
+fn location(day: &Day5, seed: u64) -> u64 {
+    day.almanac()
+        .maps()
+        .iter()
+        .fold(seed, |value, map| apply(map, value))
+}
+
+pub fn part_one(day: &Day5) -> u64 {
+    day.almanac()
+        .seeds()
+        .iter()
+        .map(|&x| location(day, x))
+        .min()
+        .unwrap()
+}
+
+/// Follows every single seed in every range.
+pub fn part_two(day: &Day5) -> Option<u64> {
+    day.almanac()
+        .seeds()
+        .chunks_exact(2)
+        .flat_map(|x| x[0]..x[0] + x[1])
+        .map(|x| location(day, x))
+        .min()
+}
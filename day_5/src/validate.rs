@@ -0,0 +1,323 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{parse_header, Range};
+
+/// The categories in the puzzle's almanac, from the first map's source to
+/// the last map's destination.
+pub const CATEGORIES: &[&str] = &[
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// Something wrong with an almanac. Lines are numbered from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Problem {
+    BadHeader {
+        line: usize,
+    },
+    UnknownCategory {
+        line: usize,
+        category: String,
+    },
+    BadRange {
+        line: usize,
+    },
+    EmptyMap {
+        line: usize,
+    },
+    /// A range whose source or destination runs past `u64::MAX`.
+    Overflow {
+        line: usize,
+    },
+    /// A range whose source overlaps the one on line `other`.
+    Overlap {
+        line: usize,
+        other: usize,
+    },
+    NoMapFrom {
+        category: String,
+    },
+    SeveralMapsFrom {
+        category: String,
+        lines: Vec<usize>,
+    },
+    /// A map going back to a category the chain has already been through.
+    Loop {
+        line: usize,
+    },
+    /// A map which isn't needed to get from the first category to the last.
+    Unused {
+        line: usize,
+    },
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::BadHeader { line } => write!(
+                f,
+                "line {}: expected '<source>-to-<destination> map:'",
+                line
+            ),
+            Problem::UnknownCategory { line, category } => {
+                write!(f, "line {}: unknown category '{}'", line, category)
+            }
+            Problem::BadRange { line } => {
+                write!(f, "line {}: expected destination, source and size", line)
+            }
+            Problem::EmptyMap { line } => write!(f, "line {}: map has no ranges", line),
+            Problem::Overflow { line } => write!(f, "line {}: range goes past {}", line, u64::MAX),
+            Problem::Overlap { line, other } => {
+                write!(f, "line {}: source overlaps line {}", line, other)
+            }
+            Problem::NoMapFrom { category } => write!(f, "no map from '{}'", category),
+            Problem::SeveralMapsFrom { category, lines } => {
+                let lines: Vec<_> = lines.iter().map(|x| x.to_string()).collect();
+                write!(
+                    f,
+                    "lines {}: more than one map from '{}'",
+                    lines.join(", "),
+                    category
+                )
+            }
+            Problem::Loop { line } => {
+                write!(f, "line {}: map leads back to an earlier category", line)
+            }
+            Problem::Unused { line } => write!(
+                f,
+                "line {}: map isn't on the way from {} to {}",
+                line,
+                CATEGORIES[0],
+                CATEGORIES[CATEGORIES.len() - 1]
+            ),
+        }
+    }
+}
+
+/// One `X-to-Y map:` section, with the line each range is on.
+struct Section<'a> {
+    line: usize,
+    categories: Option<(&'a str, &'a str)>,
+    ranges: Vec<(usize, Range)>,
+}
+
+fn parse_range(line: &str) -> Option<Range> {
+    let numbers = line
+        .split_whitespace()
+        .map(|x| x.parse().ok())
+        .collect::<Option<Vec<u64>>>()?;
+    let &[dest_start, source_start, size] = numbers.as_slice() else {
+        return None;
+    };
+
+    Some(Range {
+        dest_start,
+        source_start,
+        size,
+    })
+}
+
+/// Overlapping and overflowing ranges in a map.
+fn check_ranges(ranges: &[(usize, Range)], problems: &mut Vec<Problem>) {
+    for &(line, range) in ranges.iter() {
+        if range.overflows() {
+            problems.push(Problem::Overflow { line });
+        }
+    }
+
+    let mut ranges: Vec<_> = ranges.iter().filter(|(_, x)| x.size > 0).collect();
+    ranges.sort_by_key(|(line, x)| (x.source_start, *line));
+
+    // The earlier ranges which haven't ended yet, with their lines, which a
+    // range overlaps all of.
+    let mut active: Vec<(usize, u128)> = vec![];
+    for &&(line, range) in ranges.iter() {
+        let start = range.source_start as u128;
+        active.retain(|&(_, end)| start < end);
+        for &(other, _) in active.iter() {
+            problems.push(Problem::Overlap {
+                line: line.max(other),
+                other: line.min(other),
+            });
+        }
+        active.push((line, start + range.size as u128));
+    }
+}
+
+/// Checks that the maps follow on from one another by their categories,
+/// from the first of [`CATEGORIES`] to the last, whatever order they're in.
+fn check_chain(sections: &[Section], problems: &mut Vec<Problem>) {
+    let (first, last) = (CATEGORIES[0], CATEGORIES[CATEGORIES.len() - 1]);
+
+    let mut used = HashSet::new();
+    let mut visited = HashSet::from([first]);
+    let mut category = first;
+    while category != last {
+        let from: Vec<_> = sections
+            .iter()
+            .filter(|x| x.categories.is_some_and(|(source, _)| source == category))
+            .collect();
+
+        let section = match from.as_slice() {
+            [] => {
+                problems.push(Problem::NoMapFrom {
+                    category: category.to_string(),
+                });
+                return;
+            }
+            [section] => section,
+            _ => {
+                problems.push(Problem::SeveralMapsFrom {
+                    category: category.to_string(),
+                    lines: from.iter().map(|x| x.line).collect(),
+                });
+                return;
+            }
+        };
+
+        let (_, destination) = section.categories.unwrap();
+        if !visited.insert(destination) {
+            problems.push(Problem::Loop { line: section.line });
+            return;
+        }
+        used.insert(section.line);
+        category = destination;
+    }
+
+    for section in sections.iter().filter(|x| !used.contains(&x.line)) {
+        problems.push(Problem::Unused { line: section.line });
+    }
+}
+
+/// Everything wrong with an almanac, rather than just the first thing like
+/// parsing it. The seeds line isn't checked.
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = vec![];
+    let mut sections: Vec<Section> = vec![];
+
+    for (idx, text) in input.lines().enumerate().skip(1) {
+        let line = idx + 1;
+        if text.trim().is_empty() {
+            continue;
+        }
+
+        if !text.starts_with(|x: char| x.is_ascii_digit()) {
+            let categories = parse_header(text);
+            match categories {
+                None => problems.push(Problem::BadHeader { line }),
+                Some((source, destination)) => {
+                    for category in [source, destination] {
+                        if !CATEGORIES.contains(&category) {
+                            problems.push(Problem::UnknownCategory {
+                                line,
+                                category: category.to_string(),
+                            });
+                        }
+                    }
+                }
+            }
+            sections.push(Section {
+                line,
+                categories,
+                ranges: vec![],
+            });
+            continue;
+        }
+
+        match (parse_range(text), sections.last_mut()) {
+            (Some(range), Some(section)) => section.ranges.push((line, range)),
+            _ => problems.push(Problem::BadRange { line }),
+        }
+    }
+
+    for section in sections.iter() {
+        if section.ranges.is_empty() {
+            problems.push(Problem::EmptyMap { line: section.line });
+        }
+        check_ranges(&section.ranges, &mut problems);
+    }
+    check_chain(&sections, &mut problems);
+
+    problems
+}
+
+#[test]
+fn check_validate() {
+    assert_eq!(validate(crate::TEST_INPUT), []);
+
+    let input = "seeds: 1 2
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 16
+
+seed-to-soil map:
+18446744073709551615 5 2
+1 2
+
+seed-to-dirt map:
+
+fertilizer-to-location map:
+1 2 3
+5 10 10
+5 0 11
+7 10 1";
+
+    assert_eq!(
+        validate(input),
+        [
+            Problem::BadRange { line: 10 },
+            Problem::UnknownCategory {
+                line: 12,
+                category: "dirt".to_string()
+            },
+            Problem::Overlap { line: 6, other: 4 },
+            Problem::Overflow { line: 9 },
+            Problem::EmptyMap { line: 12 },
+            Problem::Overlap {
+                line: 17,
+                other: 15
+            },
+            Problem::Overlap {
+                line: 17,
+                other: 16
+            },
+            Problem::Overlap {
+                line: 18,
+                other: 17
+            },
+            Problem::Overlap {
+                line: 18,
+                other: 16
+            },
+            Problem::SeveralMapsFrom {
+                category: "seed".to_string(),
+                lines: vec![8, 12]
+            },
+        ]
+    );
+
+    let input = "seeds: 1\n\nseed-to-location map:\n18446744073709551615 5 1";
+    assert_eq!(validate(input), []);
+    let input = "seeds: 1\n\nseed-to-location map:\n1 2 3\n\nsoil-to-seed map:\n1 2 3";
+    assert_eq!(validate(input), [Problem::Unused { line: 6 }]);
+    let input = "seeds: 1\n\nseed-to-soil map:\n1 2 3\n\nsoil-to-seed map:\n1 2 3";
+    assert_eq!(validate(input), [Problem::Loop { line: 6 }]);
+    let input = "seeds: 1\n\nseed to soil:\n1 2 3";
+    assert_eq!(
+        validate(input),
+        [
+            Problem::BadHeader { line: 3 },
+            Problem::NoMapFrom {
+                category: "seed".to_string()
+            }
+        ]
+    );
+}
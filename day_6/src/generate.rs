@@ -0,0 +1,51 @@
+#[cfg(test)]
+use common::Solution;
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day6};
+
+/// A race with a record up to just past the best possible distance, and
+/// often close to it, so there are races which can't be won and records
+/// which can only be tied.
+fn race(max_time: u64) -> impl Strategy<Value = (u64, u64)> {
+    (1..=max_time).prop_flat_map(|time| {
+        let best = (time as u128 * time as u128 / 4 + 1).min(u64::MAX as u128) as u64;
+        let record = prop_oneof![0..=best, best.saturating_sub(1000)..=best];
+
+        (Just(time), record)
+    })
+}
+
+pub fn input(races: usize, max_time: u64) -> impl Strategy<Value = String> {
+    prop::collection::vec(race(max_time), 1..=races).prop_map(|races| {
+        let (times, distances): (Vec<_>, Vec<_>) = races
+            .iter()
+            .map(|&(t, d)| {
+                let width = d.to_string().len();
+                (format!("{:>width$}", t), format!("{:>width$}", d))
+            })
+            .unzip();
+
+        format!(
+            "Time:  {}\nDistance:  {}",
+            times.join("  "),
+            distances.join("  ")
+        )
+    })
+}
+
+common::reference_tests! {
+    Day6;
+    part_one: input(4, 60);
+    part_two: input(2, 99);
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn long_races_match_reference(input in input(1, u64::MAX)) {
+        let day = Day6::parse(&input).unwrap();
+        prop_assert_eq!(day.part_two(), Ok(reference::part_two(&day)));
+    }
+}
@@ -0,0 +1,154 @@
+use common::{Error, ParseError, Solution};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+static TEST_INPUT: &str = r#"Time:      7  15   30
+Distance:  9  40  200"#;
+
+fn split_input(input: &str) -> Result<(&str, &str), ParseError> {
+    let Some((time, distance)) = input.split_once('\n') else {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "expected the distances on another line",
+        ));
+    };
+    let Some((_, time)) = time.split_once(':') else {
+        return Err(ParseError::at(input, time, "no colon"));
+    };
+    let Some((_, distance)) = distance.split_once(':') else {
+        return Err(ParseError::at(input, distance, "no colon"));
+    };
+
+    Ok((time.trim(), distance.trim()))
+}
+
+fn parse_numbers(input: &str, numbers: &str) -> Result<Vec<u64>, ParseError> {
+    numbers
+        .split_whitespace()
+        .map(|x| {
+            x.parse()
+                .map_err(|_| ParseError::at(input, x, "bad number"))
+        })
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    let (time, distance) = split_input(input)?;
+
+    let times = parse_numbers(input, time)?;
+    let distances = parse_numbers(input, distance)?;
+
+    if times.len() != distances.len() {
+        return Err(ParseError::at(
+            input,
+            distance,
+            format!("expected {} distances", times.len()),
+        ));
+    }
+
+    Ok(times.into_iter().zip(distances).collect())
+}
+
+/// Holding the button for `x` goes `x * (time - x)`, which beats `distance`
+/// between the roots of `x^2 - time x + distance`, and the same way round
+/// from either end.
+fn number_of_ways_to_win(time: u64, distance: u64) -> u64 {
+    let (t, d) = (time as u128, distance as u128);
+    let beats = |x: u128| x * (t - x) > d;
+    let Some(discriminant) = (t * t).checked_sub(4 * d) else {
+        return 0;
+    };
+
+    // The integer square root can leave this one out either way.
+    let mut x = (t - discriminant.isqrt()) / 2;
+    while x > 0 && beats(x - 1) {
+        x -= 1;
+    }
+    while x <= t / 2 && !beats(x) {
+        x += 1;
+    }
+
+    if x > t / 2 {
+        0
+    } else {
+        (t - 2 * x + 1) as u64
+    }
+}
+
+fn first_task(races: &[(u64, u64)]) -> u64 {
+    races
+        .iter()
+        .map(|&(t, d)| number_of_ways_to_win(t, d))
+        .product()
+}
+
+#[test]
+fn check_first_task() {
+    assert_eq!(Day6::solve_part_one(TEST_INPUT), Ok(288));
+}
+
+fn parse_input_2(input: &str) -> Result<(u64, u64), ParseError> {
+    let (time, distance) = split_input(input)?;
+
+    let parse = |numbers: &str| {
+        numbers
+            .split_whitespace()
+            .collect::<String>()
+            .parse()
+            .map_err(|_| ParseError::at(input, numbers, "bad number"))
+    };
+
+    Ok((parse(time)?, parse(distance)?))
+}
+
+#[test]
+fn check_second_task() {
+    assert_eq!(Day6::solve_part_two(TEST_INPUT), Ok(71503));
+    assert_eq!(
+        Day6::solve_part_two("Time: 12345 12345 12345 12345\nDistance: 1 1 1 1"),
+        Ok(12345123451234512344)
+    );
+    assert_eq!(
+        Day6::solve_part_two("Time: 18446744073709551615\nDistance: 0"),
+        Ok(18446744073709551614)
+    );
+}
+
+#[test]
+fn check_parse_error() {
+    let error = Day6::parse("Time: 7 15 30").err().unwrap();
+    assert_eq!((error.line, error.column), (1, 14));
+    assert_eq!(error.text, "");
+}
+
+pub struct Day6 {
+    races: Vec<(u64, u64)>,
+    race: (u64, u64),
+}
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day6 {
+            races: parse_input(input)?,
+            race: parse_input_2(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u64, Error> {
+        Ok(first_task(&self.races))
+    }
+
+    fn part_two(&self) -> Result<u64, Error> {
+        Ok(number_of_ways_to_win(self.race.0, self.race.1))
+    }
+}
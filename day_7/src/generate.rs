@@ -0,0 +1,25 @@
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day7};
+
+/// Hands from only some of the cards, so that there are plenty of pairs,
+/// jokers and hands which tie on their kind.
+pub fn input() -> impl Strategy<Value = String> {
+    let hand = prop::collection::vec(prop::sample::select(&b"239TJQKA"[..]), 5)
+        .prop_map(|x| String::from_utf8(x).unwrap());
+
+    prop::collection::vec((hand, 1..=1000u64), 1..=10).prop_map(|hands| {
+        let lines: Vec<_> = hands
+            .iter()
+            .map(|(hand, bid)| format!("{} {}", hand, bid))
+            .collect();
+        lines.join("\n")
+    })
+}
+
+common::reference_tests! {
+    Day7;
+    part_one: input();
+    part_two: input();
+}
@@ -0,0 +1,50 @@
+use common::{Error, ParseError, Solution};
+
+mod first;
+mod second;
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+const TEST_INPUT: &str = r#"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483"#;
+
+pub struct Day7 {
+    first: Vec<first::HandBid>,
+    second: Vec<second::HandBid>,
+}
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day7 {
+            first: first::parse_hands(input)?,
+            second: second::parse_hands(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u64, Error> {
+        Ok(first::first_task(&self.first))
+    }
+
+    fn part_two(&self) -> Result<u64, Error> {
+        Ok(second::second_task(&self.second))
+    }
+}
+
+#[test]
+fn check_parse_error() {
+    let error = Day7::parse("32T3K 765\nKK6X7 28").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 4));
+    assert_eq!(error.text, "X");
+}
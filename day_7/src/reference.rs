@@ -0,0 +1,61 @@
+use crate::Day7;
+
+const JACK: u32 = 11;
+
+/// How many there are of each card, most first, which sort in the same
+/// order as the kinds of hand.
+fn kind(cards: &[u32]) -> Vec<usize> {
+    let mut counts: Vec<_> = (2..=14)
+        .map(|x| cards.iter().filter(|&&y| y == x).count())
+        .filter(|&x| x > 0)
+        .collect();
+    counts.sort_by(|a, b| b.cmp(a));
+
+    counts
+}
+
+/// The best kind of hand with the jokers standing in for anything. Nothing
+/// beats them standing in for another card in the hand, so that's all
+/// which gets tried.
+fn best_kind(cards: [u32; 5]) -> Vec<usize> {
+    let Some(joker) = cards.iter().position(|&x| x == JACK) else {
+        return kind(&cards);
+    };
+
+    let mut others: Vec<_> = cards.iter().copied().filter(|&x| x != JACK).collect();
+    if others.is_empty() {
+        others.push(14);
+    }
+
+    others
+        .iter()
+        .map(|&x| {
+            let mut cards = cards;
+            cards[joker] = x;
+            best_kind(cards)
+        })
+        .max()
+        .unwrap()
+}
+
+/// Each bid times the hand's place in `order`, counting from the weakest.
+fn winnings<K: Ord>(day: &Day7, order: impl Fn([u32; 5]) -> K) -> u64 {
+    let mut hands: Vec<_> = day.first.iter().map(|x| (x.cards(), x.bid())).collect();
+    hands.sort_by_key(|&(cards, _)| order(cards));
+
+    (1..).zip(hands).map(|(rank, (_, bid))| rank * bid).sum()
+}
+
+pub fn part_one(day: &Day7) -> u64 {
+    winnings(day, |cards| (kind(&cards), cards))
+}
+
+/// Jacks are jokers, and worth less than any other card.
+pub fn part_two(day: &Day7) -> u64 {
+    winnings(day, |cards| {
+        (
+            best_kind(cards),
+            cards.map(|x| if x == JACK { 1 } else { x }),
+        )
+    })
+}
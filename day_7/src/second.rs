@@ -1,7 +1,6 @@
-use std::{
-    collections::{HashMap, HashSet},
-    str::FromStr,
-};
+use std::{collections::HashMap, str::FromStr};
+
+use common::ParseError;
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
 enum Rank {
@@ -54,7 +53,7 @@ impl Rank {
             return Rank::OnePair;
         }
 
-        return Rank::HighCard;
+        Rank::HighCard
     }
 }
 
@@ -78,7 +77,7 @@ impl CardValue {
             'Q' => value(12),
             'K' => value(13),
             'A' => value(14),
-            _ => return Err("not good"),
+            _ => return Err("bad card"),
         })
     }
 }
@@ -90,19 +89,20 @@ struct Hand {
 }
 
 impl FromStr for Hand {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let char_values: Result<Vec<_>, _> = s.chars().map(|c| CardValue::from_char(c)).collect();
-        let char_values = char_values?;
-
-        let hand: [CardValue; 5] = [
-            char_values[0],
-            char_values[1],
-            char_values[2],
-            char_values[3],
-            char_values[4],
-        ];
+        let char_values = s
+            .char_indices()
+            .map(|(idx, c)| {
+                CardValue::from_char(c)
+                    .map_err(|e| ParseError::at(s, &s[idx..idx + c.len_utf8()], e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let hand: [CardValue; 5] = char_values
+            .try_into()
+            .map_err(|_| ParseError::new(s, "a hand must have 5 cards"))?;
 
         Ok(Hand {
             rank: Rank::generate_rank(&hand),
@@ -112,38 +112,51 @@ impl FromStr for Hand {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
-struct HandBid {
+pub struct HandBid {
     hand: Hand,
     bid: u64,
 }
 
 impl FromStr for HandBid {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (hand, bid) = s.split_once(' ').ok_or("can't split a line")?;
+        let (hand, bid) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(s, "can't split a line"))?;
 
         Ok(HandBid {
-            hand: hand.parse()?,
-            bid: bid.parse().map_err(|_| "can't make number")?,
+            hand: hand.parse().map_err(|e: ParseError| e.within(s, hand))?,
+            bid: bid
+                .parse()
+                .map_err(|_| ParseError::at(s, bid, "can't make number"))?,
         })
     }
 }
 
-pub fn second_task(input: &str) -> Result<u64, &'static str> {
-    let hands: Result<Vec<HandBid>, _> = input.lines().map(|line| line.parse()).collect();
-    let mut hands = hands?;
+pub fn parse_hands(input: &str) -> Result<Vec<HandBid>, ParseError> {
+    input
+        .lines()
+        .map(|line| line.parse().map_err(|e: ParseError| e.within(input, line)))
+        .collect()
+}
+
+pub fn second_task(hands: &[HandBid]) -> u64 {
+    let mut hands = hands.to_vec();
 
     hands.sort_by_key(|x| x.hand);
 
-    Ok(hands
+    hands
         .iter()
         .enumerate()
         .map(|(idx, hand)| hand.bid * (idx as u64 + 1))
-        .sum())
+        .sum()
 }
 
 #[test]
 fn check_second_task() {
-    assert_eq!(second_task(crate::TEST_INPUT), Ok(5905));
+    assert_eq!(
+        parse_hands(crate::TEST_INPUT).map(|x| second_task(&x)),
+        Ok(5905)
+    );
 }
@@ -0,0 +1,105 @@
+#[cfg(test)]
+use common::{Error, Solution};
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day8};
+
+/// The nodes for one ghost, which steps from its start through `lead_in`
+/// other nodes into a loop through its end. The loop is a whole number of
+/// trips through the directions, and with no lead-in it takes as long as
+/// getting to the end the first time, like the puzzle input. Turning the
+/// wrong way leads to `XXX`, or nowhere new.
+fn ghost(
+    ghost: usize,
+    directions: &[u8],
+    laps: usize,
+    lead_in: usize,
+    dead_ends: &[bool],
+) -> Vec<(String, String, String)> {
+    let (start, end) = match ghost {
+        0 => ("AAA".to_string(), "ZZZ".to_string()),
+        _ => (format!("{0}{0}A", ghost), format!("{0}{0}Z", ghost)),
+    };
+    let length = laps * directions.len();
+
+    // the loop the ghost goes around, finishing at its end
+    let mut nodes: Vec<_> = (1..length).map(|x| format!("{}{:02}", ghost, x)).collect();
+    nodes.push(end);
+
+    let path: Vec<_> = std::iter::once(start)
+        .chain((1..=lead_in).map(|x| format!("{}L{}", ghost, x)))
+        .chain(nodes)
+        .collect();
+
+    path.iter()
+        .enumerate()
+        .map(|(idx, name)| {
+            let next = path.get(idx + 1).unwrap_or(&path[1 + lead_in]).clone();
+            let other = if dead_ends[idx] {
+                "XXX".to_string()
+            } else {
+                next.clone()
+            };
+            let (left, right) = match directions[idx % directions.len()] {
+                b'L' => (next, other),
+                _ => (other, next),
+            };
+            (name.clone(), left, right)
+        })
+        .collect()
+}
+
+/// Inputs whose ghosts each have a lead-in from `lead_in`. Part 2 only has
+/// an answer the quick way when none of them do.
+pub fn input(lead_in: std::ops::RangeInclusive<usize>) -> impl Strategy<Value = String> {
+    let directions = prop::collection::vec(prop::sample::select(&b"LR"[..]), 1..=4);
+    let ghosts = prop::collection::vec(
+        (
+            1..=5usize,
+            lead_in,
+            prop::collection::vec(any::<bool>(), 24),
+        ),
+        1..=4,
+    );
+
+    (directions, ghosts)
+        .prop_flat_map(|(directions, ghosts)| {
+            let mut nodes: Vec<_> = ghosts
+                .iter()
+                .enumerate()
+                .flat_map(|(idx, (laps, lead_in, dead_ends))| {
+                    ghost(idx, &directions, *laps, *lead_in, dead_ends)
+                })
+                .map(|(name, left, right)| format!("{} = ({}, {})", name, left, right))
+                .collect();
+            nodes.push("XXX = (XXX, XXX)".to_string());
+
+            let directions = String::from_utf8(directions).unwrap();
+            (Just(directions), Just(nodes).prop_shuffle())
+        })
+        .prop_map(|(directions, nodes)| format!("{}\n\n{}", directions, nodes.join("\n")))
+}
+
+common::reference_tests! {
+    Day8;
+    part_one: input(0..=2);
+    part_two: input(0..=0);
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn second_task_rejects_ghosts_out_of_step(input in input(1..=2)) {
+        // unless they all get to an end together the first time round, in
+        // which case the reference finds that quickly
+        let day = Day8::parse(&input).unwrap();
+        match day.part_two() {
+            Ok(steps) => prop_assert_eq!(steps, reference::part_two(&day)),
+            Err(e) => prop_assert_eq!(
+                e,
+                Error::NoSolution("the ghosts don't each loop back to their end in step")
+            ),
+        }
+    }
+}
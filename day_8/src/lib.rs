@@ -0,0 +1,316 @@
+use std::{collections::HashMap, fmt::Debug, str::FromStr};
+
+use common::{Error, ParseError, Solution};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+const ALT_TEST_INPUT: &str = r#"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)"#;
+
+#[cfg(test)]
+const TEST_INPUT: &str = r#"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"#;
+
+#[cfg(test)]
+const TEST_2_INPUT: &str = r#"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"#;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+struct Location {
+    code: [u8; 3],
+}
+
+impl Debug for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Location")
+            .field("code", &core::str::from_utf8(&self.code).unwrap_or("---"))
+            .finish()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Node {
+    left: Location,
+    right: Location,
+}
+
+impl FromStr for Location {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 3 {
+            return Err("bad location");
+        }
+
+        let code = s.as_bytes().try_into().map_err(|_| "shouldn't happen")?;
+
+        Ok(Location { code })
+    }
+}
+
+fn parse_location(input: &str, location: &str) -> Result<Location, ParseError> {
+    location
+        .parse()
+        .map_err(|e| ParseError::at(input, location, e))
+}
+
+fn construct_map(input: &str) -> Result<HashMap<Location, Node>, ParseError> {
+    let mut map = HashMap::new();
+    let mut references = Vec::new();
+
+    for l in input.lines() {
+        let (location, lr) = l
+            .split_once(" = ")
+            .ok_or_else(|| ParseError::at(input, l, "expected 'AAA = (BBB, CCC)'"))?;
+
+        let (left, right) = lr
+            .strip_prefix('(')
+            .and_then(|lr| lr.strip_suffix(')'))
+            .and_then(|lr| lr.split_once(", "))
+            .ok_or_else(|| ParseError::at(input, lr, "expected '(BBB, CCC)'"))?;
+
+        if map
+            .insert(
+                parse_location(input, location)?,
+                Node {
+                    left: parse_location(input, left)?,
+                    right: parse_location(input, right)?,
+                },
+            )
+            .is_some()
+        {
+            return Err(ParseError::at(input, location, "duplicate location"));
+        }
+        references.extend([left, right]);
+    }
+
+    if let Some(missing) = references
+        .into_iter()
+        .find(|x| !map.contains_key(&x.parse().unwrap()))
+    {
+        return Err(ParseError::at(input, missing, "unknown location"));
+    }
+
+    Ok(map)
+}
+
+fn first_task(directions: &str, map: &HashMap<Location, Node>) -> Result<u64, Error> {
+    let mut current_node: Location = "AAA".parse().unwrap();
+    let target_node: Location = "ZZZ".parse().unwrap();
+
+    if !map.contains_key(&current_node) {
+        return Err(Error::NoSolution("there is no node AAA"));
+    }
+
+    // By then every node has been visited at every point in the directions,
+    // so it's going round without ever getting to ZZZ.
+    let limit = (map.len() * directions.len()) as u64;
+
+    for (direction, count) in core::iter::repeat(directions.as_bytes())
+        .flatten()
+        .copied()
+        .zip(1..=limit)
+    {
+        let node = map.get(&current_node).unwrap();
+        let next_node = match direction {
+            b'L' => node.left,
+            b'R' => node.right,
+            _ => panic!("bad direction"),
+        };
+
+        if next_node == target_node {
+            return Ok(count);
+        }
+
+        current_node = next_node;
+    }
+
+    Err(Error::NoSolution("ZZZ can't be reached from AAA"))
+}
+
+#[test]
+fn check_first_task() {
+    assert_eq!(Day8::solve_part_one(TEST_INPUT), Ok(6));
+    assert_eq!(Day8::solve_part_one(ALT_TEST_INPUT), Ok(2));
+    assert_eq!(
+        Day8::solve_part_one("L\n\nAAA = (AAA, AAA)"),
+        Err(Error::NoSolution("ZZZ can't be reached from AAA"))
+    );
+}
+
+/// The next time after `start` that a ghost at `location` gets to an end,
+/// and which end it is.
+fn next_end(
+    mut location: Location,
+    start: u64,
+    nodes: &HashMap<Location, Node>,
+    directions: &[u8],
+) -> Option<(u64, Location)> {
+    // By then the ghost has been in every node at every point in the
+    // directions, so it's going round without ever getting to an end.
+    let limit = start + (nodes.len() * directions.len()) as u64;
+
+    for count in start + 1..=limit {
+        let node = nodes.get(&location).unwrap();
+        location = match directions[((count - 1) % directions.len() as u64) as usize] {
+            b'L' => node.left,
+            _ => node.right,
+        };
+
+        if location.code[2] == b'Z' {
+            return Some((count, location));
+        }
+    }
+
+    None
+}
+
+/// Whether a ghost which first gets to `end` after `first` steps is then at
+/// an end at every multiple of that time and no other. That's what makes the
+/// LCM of the ghosts' first times the answer.
+fn loops_in_step(
+    first: u64,
+    end: Location,
+    nodes: &HashMap<Location, Node>,
+    directions: &[u8],
+) -> bool {
+    // Once the ghost is back at the same end at the same point in the
+    // directions, it goes round the same way forever.
+    let len = directions.len() as u64;
+    let laps = len / num::integer::gcd(first, len);
+    let mut location = end;
+    for lap in 2..=laps + 1 {
+        match next_end(location, (lap - 1) * first, nodes, directions) {
+            Some((count, next)) if count == lap * first => location = next,
+            _ => return false,
+        }
+    }
+
+    location == end
+}
+
+fn second_task(directions: &str, map: &HashMap<Location, Node>) -> Result<u64, Error> {
+    let directions = directions.as_bytes();
+    let ends = map
+        .keys()
+        .filter(|x| x.code[2] == b'A')
+        .map(|&l| next_end(l, 0, map, directions))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(Error::NoSolution("a ghost never gets to an end"))?;
+
+    let Some(&(first, _)) = ends.first() else {
+        return Err(Error::NoSolution("there are no nodes ending in A"));
+    };
+    // All at an end together the first time round, wherever they go next.
+    if ends.iter().all(|&(x, _)| x == first) {
+        return Ok(first);
+    }
+
+    if !ends
+        .iter()
+        .all(|&(first, end)| loops_in_step(first, end, map, directions))
+    {
+        return Err(Error::NoSolution(
+            "the ghosts don't each loop back to their end in step",
+        ));
+    }
+
+    Ok(ends.into_iter().map(|(x, _)| x).fold(1, num::integer::lcm))
+}
+
+#[test]
+fn check_second_task() {
+    assert_eq!(Day8::solve_part_two(TEST_2_INPUT), Ok(6));
+    assert_eq!(Day8::solve_part_two(TEST_INPUT), Ok(6));
+    assert!(Day8::solve_part_two("L\n\nBBB = (BBB, BBB)").is_err());
+    assert!(Day8::solve_part_two("L\n\nAAA = (BBB, BBB)\nBBB = (BBB, BBB)").is_err());
+    // gets to the end after 2 steps and then every 1
+    assert_eq!(
+        Day8::solve_part_two("L\n\nAAA = (BBB, BBB)\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)"),
+        Ok(2)
+    );
+    // and the other ghost only after 1
+    assert!(Day8::solve_part_two(
+        "L\n\nAAA = (BBB, BBB)\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11B, 11B)"
+    )
+    .is_err());
+}
+
+#[test]
+fn check_parse_error() {
+    let error = Day8::parse("LR\nAAA = (BBB, BBB)").err().unwrap();
+    assert_eq!((error.line, error.column), (1, 1));
+    assert_eq!(error.text, "LR");
+}
+
+pub struct Day8 {
+    directions: String,
+    map: HashMap<Location, Node>,
+}
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let Some((directions, nodes)) = input.split_once("\n\n") else {
+            let directions = input.lines().next().unwrap_or_default();
+            return Err(ParseError::at(
+                input,
+                directions,
+                "no blank line after the directions",
+            ));
+        };
+
+        if directions.is_empty() {
+            return Err(ParseError::new(directions, "no directions"));
+        }
+        if let Some((idx, c)) = directions
+            .char_indices()
+            .find(|&(_, c)| c != 'L' && c != 'R')
+        {
+            return Err(ParseError::at(
+                input,
+                &directions[idx..idx + c.len_utf8()],
+                "direction must be L or R",
+            ));
+        }
+
+        Ok(Day8 {
+            directions: directions.to_string(),
+            map: construct_map(nodes).map_err(|e| e.within(input, nodes))?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u64, Error> {
+        first_task(&self.directions, &self.map)
+    }
+
+    fn part_two(&self) -> Result<u64, Error> {
+        second_task(&self.directions, &self.map)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{Day8, Location};
+
+/// Moves every ghost in step until they are all at an end at once.
+fn steps(day: &Day8, mut ghosts: Vec<Location>, is_end: impl Fn(&Location) -> bool) -> u64 {
+    let mut count = 0;
+    for direction in day.directions.bytes().cycle() {
+        if ghosts.iter().all(&is_end) {
+            break;
+        }
+
+        for ghost in ghosts.iter_mut() {
+            let node = &day.map[ghost];
+            *ghost = if direction == b'L' {
+                node.left
+            } else {
+                node.right
+            };
+        }
+        count += 1;
+    }
+
+    count
+}
+
+pub fn part_one(day: &Day8) -> u64 {
+    let end = "ZZZ".parse().unwrap();
+    steps(day, vec!["AAA".parse().unwrap()], |&x| x == end)
+}
+
+pub fn part_two(day: &Day8) -> u64 {
+    let starts = day.map.keys().filter(|x| x.code[2] == b'A').copied();
+    steps(day, starts.collect(), |x| x.code[2] == b'Z')
+}
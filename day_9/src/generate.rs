@@ -0,0 +1,37 @@
+use proptest::prelude::*;
+
+#[cfg(test)]
+use crate::{reference, Day9};
+
+/// A sequence from a polynomial with small coefficients, like the puzzle's,
+/// or just any numbers, which the polynomial through them has to fit.
+fn sequence() -> impl Strategy<Value = Vec<i64>> {
+    let polynomial =
+        (prop::collection::vec(-5..=5i64, 1..=4), 1..=8usize).prop_map(|(coefficients, len)| {
+            (0..len as i64)
+                .map(|x| coefficients.iter().rev().fold(0, |acc, c| acc * x + c))
+                .collect()
+        });
+    let any = prop::collection::vec(-20..=20i64, 1..=8);
+
+    prop_oneof![polynomial, any]
+}
+
+pub fn input() -> impl Strategy<Value = String> {
+    prop::collection::vec(sequence(), 1..=5).prop_map(|sequences| {
+        let lines: Vec<_> = sequences
+            .iter()
+            .map(|x| {
+                let numbers: Vec<_> = x.iter().map(|x| x.to_string()).collect();
+                numbers.join(" ")
+            })
+            .collect();
+        lines.join("\n")
+    })
+}
+
+common::reference_tests! {
+    Day9;
+    part_one: input();
+    part_two: input();
+}
@@ -0,0 +1,110 @@
+use common::{Error, ParseError, Solution};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+const TEST_INPUT: &str = r#"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"#;
+
+fn generate_reduced_numbers(numbers: Vec<i64>) -> Vec<Vec<i64>> {
+    let mut reduced_numbers: Vec<Vec<i64>> = Vec::new();
+    reduced_numbers.push(numbers);
+
+    loop {
+        let last = reduced_numbers.last().unwrap();
+        {
+            let f = last[0];
+            if last.iter().all(|x| *x == f) {
+                break;
+            }
+        }
+
+        reduced_numbers.push(
+            last.windows(2)
+                .map(|window| window[1] - window[0])
+                .collect(),
+        );
+    }
+
+    reduced_numbers
+}
+
+fn first_task(sequences: &[Vec<i64>]) -> i64 {
+    sequences
+        .iter()
+        .map(|numbers| {
+            generate_reduced_numbers(numbers.clone())
+                .iter()
+                .filter_map(|x| x.last())
+                .sum::<i64>()
+        })
+        .sum()
+}
+
+fn second_task(sequences: &[Vec<i64>]) -> i64 {
+    sequences
+        .iter()
+        .map(|numbers| {
+            let reduced = generate_reduced_numbers(numbers.clone())
+                .iter()
+                .rev()
+                .filter_map(|x| x.first())
+                .copied()
+                .reduce(|acc, a| a - acc)
+                .unwrap();
+            reduced
+        })
+        .sum()
+}
+
+#[test]
+fn check_first_task() {
+    assert_eq!(Day9::solve_part_one(TEST_INPUT), Ok(114));
+}
+
+#[test]
+fn check_second_task() {
+    assert_eq!(Day9::solve_part_two(TEST_INPUT), Ok(2));
+}
+
+pub struct Day9 {
+    sequences: Vec<Vec<i64>>,
+}
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day9 {
+            sequences: input
+                .lines()
+                .map(|line| {
+                    if line.trim().is_empty() {
+                        return Err(ParseError::at(input, line, "empty sequence"));
+                    }
+                    line.split_whitespace()
+                        .map(|x| {
+                            x.parse::<i64>()
+                                .map_err(|_| ParseError::at(input, x, "bad number"))
+                        })
+                        .collect()
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn part_one(&self) -> Result<i64, Error> {
+        Ok(first_task(&self.sequences))
+    }
+
+    fn part_two(&self) -> Result<i64, Error> {
+        Ok(second_task(&self.sequences))
+    }
+}
@@ -0,0 +1,32 @@
+use crate::Day9;
+
+/// The value at `x` of the lowest degree polynomial through the sequence,
+/// whose values are at `0, 1, 2...`, by Lagrange interpolation.
+fn extrapolate(values: &[i64], x: i128) -> i128 {
+    let n = values.len() as i128;
+
+    (0..n)
+        .zip(values)
+        .map(|(i, &value)| {
+            // the product of (x - j) / (i - j) for every other j
+            let (top, bottom) = (0..n)
+                .filter(|&j| j != i)
+                .fold((1, 1), |(top, bottom), j| (top * (x - j), bottom * (i - j)));
+            value as i128 * top / bottom
+        })
+        .sum()
+}
+
+pub fn part_one(day: &Day9) -> i64 {
+    let total: i128 = day
+        .sequences
+        .iter()
+        .map(|x| extrapolate(x, x.len() as i128))
+        .sum();
+    total as i64
+}
+
+pub fn part_two(day: &Day9) -> i64 {
+    let total: i128 = day.sequences.iter().map(|x| extrapolate(x, -1)).sum();
+    total as i64
+}
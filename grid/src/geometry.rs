@@ -0,0 +1,166 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Vector2d(pub i32, pub i32);
+
+impl Vector2d {
+    pub fn manhattan(self) -> i32 {
+        self.0.abs() + self.1.abs()
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> i32 {
+        (self - other).manhattan()
+    }
+}
+
+impl From<(i32, i32)> for Vector2d {
+    fn from(value: (i32, i32)) -> Self {
+        Vector2d(value.0, value.1)
+    }
+}
+
+impl From<Vector2d> for (i32, i32) {
+    fn from(value: Vector2d) -> Self {
+        (value.0, value.1)
+    }
+}
+
+impl Add for Vector2d {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vector2d(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl AddAssign for Vector2d {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vector2d {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector2d(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl SubAssign for Vector2d {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i32> for Vector2d {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Vector2d(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl Neg for Vector2d {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Vector2d(-self.0, -self.1)
+    }
+}
+
+/// A compass direction on a grid, with north pointing towards `y = 0`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn all() -> impl Iterator<Item = Direction> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn to_vector(self) -> Vector2d {
+        match self {
+            Direction::North => Vector2d(0, -1),
+            Direction::East => Vector2d(1, 0),
+            Direction::South => Vector2d(0, 1),
+            Direction::West => Vector2d(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::East | Direction::West)
+    }
+}
+
+impl From<Direction> for Vector2d {
+    fn from(value: Direction) -> Self {
+        value.to_vector()
+    }
+}
+
+impl From<Direction> for usize {
+    fn from(value: Direction) -> Self {
+        value as usize
+    }
+}
+
+impl TryFrom<Vector2d> for Direction {
+    type Error = Vector2d;
+
+    fn try_from(value: Vector2d) -> Result<Self, Self::Error> {
+        Direction::all()
+            .find(|x| x.to_vector() == value)
+            .ok_or(value)
+    }
+}
+
+#[test]
+fn check_vector_arithmetic() {
+    let a = Vector2d(3, -4);
+    let b = Vector2d(1, 2);
+
+    assert_eq!(a + b, Vector2d(4, -2));
+    assert_eq!(a - b, Vector2d(2, -6));
+    assert_eq!(a * 2, Vector2d(6, -8));
+    assert_eq!(-a, Vector2d(-3, 4));
+    assert_eq!(a.manhattan(), 7);
+    assert_eq!(a.manhattan_distance(b), 8);
+}
+
+#[test]
+fn check_turns() {
+    for direction in Direction::all() {
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+        assert_eq!(direction.opposite().to_vector(), -direction.to_vector());
+        assert_eq!(Direction::try_from(direction.to_vector()), Ok(direction));
+    }
+
+    assert_eq!(Direction::North.turn_right(), Direction::East);
+    assert_eq!(Direction::North.turn_left(), Direction::West);
+}
@@ -0,0 +1,321 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use common::ParseError;
+
+mod geometry;
+
+pub use geometry::{Direction, Vector2d};
+
+const NEIGHBOURS_4: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+const NEIGHBOURS_8: [(i32, i32); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells stored row by row, addressed by `(x, y)` with
+/// `(0, 0)` in the top left.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: i32,
+    height: i32,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: i32, height: i32, cells: Vec<T>) -> Self {
+        assert!(width >= 0 && height >= 0, "negative grid size");
+        assert_eq!(
+            cells.len(),
+            (width * height) as usize,
+            "cell count doesn't match a {}x{} grid",
+            width,
+            height
+        );
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn from_fn(width: i32, height: i32, mut f: impl FnMut(i32, i32) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+
+        Self::new(width, height, cells)
+    }
+
+    /// Parses one cell per character, one row per line. Every line must be
+    /// the same length.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        let width = input.lines().next().map_or(0, |x| x.chars().count());
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for line in input.lines() {
+            if line.chars().count() != width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("expected a row of width {}", width),
+                ));
+            }
+
+            for (idx, c) in line.char_indices() {
+                cells.push(
+                    cell(c)
+                        .map_err(|e| ParseError::at(input, &line[idx..idx + c.len_utf8()], e))?,
+                );
+            }
+
+            height += 1;
+        }
+
+        if cells.is_empty() {
+            return Err(ParseError::new(input, "empty grid"));
+        }
+
+        Ok(Self::new(width as i32, height, cells))
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= 0 && y >= 0 && x < self.width && y < self.height
+    }
+
+    fn index_of(&self, x: i32, y: i32) -> Option<usize> {
+        self.contains(x, y).then(|| (x + y * self.width) as usize)
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> Option<&T> {
+        self.index_of(x, y).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
+        self.index_of(x, y).map(|idx| &mut self.cells[idx])
+    }
+
+    /// Like [`Grid::get`], but gives `default` for anything off the grid.
+    pub fn get_or(&self, x: i32, y: i32, default: T) -> T
+    where
+        T: Copy,
+    {
+        self.get(x, y).copied().unwrap_or(default)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (i32, i32)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// The position of the first cell, in reading order, matching `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<(i32, i32)> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|idx| (idx as i32 % self.width, idx as i32 / self.width))
+    }
+
+    /// The orthogonal neighbours of `(x, y)` which are on the grid.
+    pub fn neighbours4(&self, x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&(x, y)| self.contains(x, y))
+    }
+
+    /// The orthogonal and diagonal neighbours of `(x, y)` which are on the
+    /// grid.
+    pub fn neighbours8(&self, x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&(x, y)| self.contains(x, y))
+    }
+
+    pub fn row(&self, y: i32) -> &[T] {
+        assert!(y >= 0 && y < self.height, "row {} out of bounds", y);
+        let start = (y * self.width) as usize;
+        &self.cells[start..start + self.width as usize]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width.max(1) as usize)
+    }
+
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> {
+        assert!(x >= 0 && x < self.width, "column {} out of bounds", x);
+        self.cells
+            .iter()
+            .skip(x as usize)
+            .step_by(self.width as usize)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Swaps rows with columns, so `(x, y)` moves to `(y, x)`.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    pub fn rotate_anticlockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of ASCII characters, keeping each as its byte.
+    pub fn parse_ascii(input: &str) -> Result<Self, ParseError> {
+        Self::parse(input, |c| {
+            if c.is_ascii() {
+                Ok(c as u8)
+            } else {
+                Err("not an ascii character")
+            }
+        })
+    }
+}
+
+impl<T> Index<(i32, i32)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (i32, i32)) -> &Self::Output {
+        match self.index_of(x, y) {
+            Some(idx) => &self.cells[idx],
+            None => panic!(
+                "out of bounds access, width {} height {}, x {} y {}",
+                self.width, self.height, x, y
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(i32, i32)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (i32, i32)) -> &mut Self::Output {
+        match self.index_of(x, y) {
+            Some(idx) => &mut self.cells[idx],
+            None => panic!(
+                "out of bounds access, width {} height {}, x {} y {}",
+                self.width, self.height, x, y
+            ),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y != 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                cell.fmt(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+static TEST_INPUT: &str = "abc
+def";
+
+#[test]
+fn check_get() {
+    let grid = Grid::parse_ascii(TEST_INPUT).unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(2, 1), Some(&b'f'));
+    assert_eq!(grid.get(3, 1), None);
+    assert_eq!(grid.get_or(-1, 0, b'.'), b'.');
+    assert_eq!(grid.row(1), b"def");
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), b"be");
+    assert_eq!(grid.position(|&x| x == b'e'), Some((1, 1)));
+}
+
+#[test]
+fn check_neighbours() {
+    let grid = Grid::parse_ascii(TEST_INPUT).unwrap();
+
+    assert_eq!(
+        grid.neighbours4(0, 0).collect::<Vec<_>>(),
+        vec![(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbours8(1, 0).count(), 5);
+}
+
+#[test]
+fn check_rotate() {
+    let grid = Grid::parse(TEST_INPUT, Ok).unwrap();
+
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+    assert_eq!(grid.rotate_anticlockwise().to_string(), "cf\nbe\nad");
+    assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+}
+
+#[test]
+fn check_parse_error() {
+    let error = Grid::parse_ascii("abc\nde").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 1));
+
+    let error = Grid::parse("ab\ncd", |c| if c == 'd' { Err("no d") } else { Ok(c) })
+        .err()
+        .unwrap();
+    assert_eq!((error.line, error.column), (2, 2));
+}